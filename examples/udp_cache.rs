@@ -2,7 +2,9 @@ use rtempest::udp::Tempest;
 
 #[tokio::main]
 async fn main() {
-    let (tempest, mut receiver) = Tempest::listen_udp_with_cache().await;
+    let (tempest, mut receiver) = Tempest::listen_udp_with_cache()
+        .await
+        .expect("Unable to bind listener");
 
     while let Some(event) = receiver.recv().await {
         println!("Event: {event:?}");
@@ -0,0 +1,22 @@
+use rtempest::udp::Tempest;
+use std::net::Ipv4Addr;
+
+#[tokio::main]
+async fn main() {
+    let (tempest, mut receiver) = Tempest::listen_udp_on(
+        Some(Ipv4Addr::new(192, 168, 1, 50).into()),
+        Some(50222),
+        true,
+        None,
+    )
+    .await
+    .expect("Unable to bind listener");
+
+    while let Some(event) = receiver.recv().await {
+        println!("Event: {event:?}");
+
+        println!("Number of stations cached: {}", tempest.station_count());
+    }
+
+    eprintln!("Channel closed");
+}
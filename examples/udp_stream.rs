@@ -0,0 +1,25 @@
+use rtempest::{data::EventType, udp::Tempest};
+use std::time::Duration;
+use tokio_stream::StreamExt;
+
+#[tokio::main]
+async fn main() {
+    let stream = Tempest::listen_udp_stream()
+        .await
+        .expect("Unable to bind listener");
+
+    // rapid wind events arrive every few seconds, only print one every 30 seconds
+    let stream = stream
+        .filter(|event| matches!(event, EventType::RapidWind(_)))
+        .throttle(Duration::from_secs(30));
+
+    tokio::pin!(stream);
+
+    while let Some(event) = stream.next().await {
+        if let EventType::RapidWind(event_data) = &event {
+            println!("{event_data}");
+        }
+    }
+
+    eprintln!("Channel closed");
+}
@@ -2,7 +2,9 @@ use rtempest::{data::EventType, udp::Tempest};
 
 #[tokio::main]
 async fn main() {
-    let mut receiver = Tempest::listen_udp_subscribe(vec!["ST-00084233"]).await;
+    let mut receiver = Tempest::listen_udp_subscribe(vec!["ST-00084233"])
+        .await
+        .expect("Unable to bind listener");
 
     while let Some(event) = receiver.recv().await {
         match &event {
@@ -30,6 +32,12 @@ async fn main() {
             EventType::HubStatus(event_data) => {
                 println!("{event_data}");
             }
+            EventType::Unknown { event_type, raw } => {
+                println!("Unknown event type {event_type}: {raw}");
+            }
+            _ => {
+                println!("{event:?}");
+            }
         }
     }
 
@@ -0,0 +1,53 @@
+//! Sources of the current time for time-dependent features
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+/// Source of the current time
+pub trait Clock: Send + Sync {
+    /// Returns the current time
+    fn now(&self) -> SystemTime;
+}
+
+/// Clock reading the system's wall-clock time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock that only moves when advanced manually, for deterministic tests
+///
+/// Clones share the same time, so a clone handed to a `Tempest` can be driven from a test.
+#[derive(Debug, Clone)]
+pub struct TestClock {
+    now: Arc<Mutex<SystemTime>>,
+}
+
+impl TestClock {
+    /// Create a clock starting at the provided time
+    pub fn new(start: SystemTime) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+
+    /// Move the clock forward by the provided duration
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().expect("Unable to acquire clock lock") += by;
+    }
+
+    /// Move the clock to the provided time
+    pub fn set(&self, to: SystemTime) {
+        *self.now.lock().expect("Unable to acquire clock lock") = to;
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().expect("Unable to acquire clock lock")
+    }
+}
@@ -1,10 +1,169 @@
 //! Data structures for managing WeatherFlow Tempest weather data
 
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use log::{debug, warn};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde_json::Value;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt;
+use std::time::{Duration, SystemTime};
+use units::{celsius_to_fahrenheit, fahrenheit_to_celsius, km_to_miles, mb_to_inhg, mps_to_mph};
+
+pub mod units;
+
+/// Number of recent readings retained per metric for computing moving averages
+///
+/// Averages computed from this window are truncated to its readings, so with a small `alpha` they are seeded from
+/// the oldest retained reading and can differ from an average spanning every reading.
+pub const EMA_SAMPLE_CAPACITY: usize = 64;
+
+/// Append a reading to a bounded sample window, discarding the oldest reading when full
+pub(crate) fn push_sample(samples: &mut VecDeque<f32>, value: Option<f32>) {
+    if let Some(value) = value {
+        if samples.len() == EMA_SAMPLE_CAPACITY {
+            samples.pop_front();
+        }
+
+        samples.push_back(value);
+    }
+}
+
+/// Create a sample window seeded with an optional first reading
+fn initial_samples(value: Option<f32>) -> VecDeque<f32> {
+    let mut samples = VecDeque::with_capacity(EMA_SAMPLE_CAPACITY);
+    push_sample(&mut samples, value);
+    samples
+}
+
+/// Metrics with recent readings retained per station
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampledMetric {
+    AirTemperature,
+    StationPressure,
+    RelativeHumidity,
+}
+
+/// Number of recent lightning strikes retained per station
+pub const LIGHTNING_HISTORY_CAPACITY: usize = 256;
+
+/// Append a lightning strike to a bounded history, discarding the oldest strike when full
+pub(crate) fn push_strike(
+    history: &mut VecDeque<LightningStrikeEvent>,
+    event: LightningStrikeEvent,
+) {
+    if history.len() == LIGHTNING_HISTORY_CAPACITY {
+        history.pop_front();
+    }
+
+    history.push_back(event);
+}
+
+/// Default number of recent rapid wind samples retained per station, about 18 minutes at the 3 second rapid wind interval
+pub const WIND_HISTORY_CAPACITY: usize = 360;
+
+/// Append a rapid wind sample to a history bounded by `capacity`, discarding the oldest samples when full
+pub(crate) fn push_wind(
+    history: &mut VecDeque<(u64, f32)>,
+    capacity: usize,
+    timestamp: u64,
+    speed: f32,
+) {
+    while history.len() >= capacity.max(1) {
+        history.pop_front();
+    }
+
+    history.push_back((timestamp, speed));
+}
+
+/// Maximum wind speeds (m/s, meters per second) over the standard gust reporting windows
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GustWindows {
+    pub max_1min: f32,
+    pub max_10min: f32,
+}
+
+impl GustWindows {
+    /// Maxima of the rapid wind samples within one and ten minutes of the most recent sample, or None if there are no samples
+    pub fn from_history(history: &VecDeque<(u64, f32)>) -> Option<Self> {
+        let &(latest, _) = history.back()?;
+
+        let max_within = |seconds: u64| {
+            history
+                .iter()
+                .filter(|(timestamp, _)| latest.saturating_sub(*timestamp) < seconds)
+                .map(|&(_, speed)| speed)
+                .fold(f32::MIN, f32::max)
+        };
+
+        Some(GustWindows {
+            max_1min: max_within(60),
+            max_10min: max_within(600),
+        })
+    }
+}
+
+/// Add the minutes spent at a temperature (C, celsius) to the per-temperature totals
+///
+/// Temperatures are bucketed to hundredths of a degree, the resolution reported by stations.
+pub(crate) fn record_temperature_minutes(
+    temperature_minutes: &mut BTreeMap<i32, f32>,
+    temperature: Option<f32>,
+    minutes: Option<f32>,
+) {
+    if let (Some(temperature), Some(minutes)) = (temperature, minutes) {
+        *temperature_minutes
+            .entry((temperature * 100.0).round() as i32)
+            .or_default() += minutes;
+    }
+}
+
+/// Create per-temperature totals seeded with an optional first observation
+fn initial_temperature_minutes(
+    temperature: Option<f32>,
+    minutes: Option<f32>,
+) -> BTreeMap<i32, f32> {
+    let mut temperature_minutes = BTreeMap::new();
+    record_temperature_minutes(&mut temperature_minutes, temperature, minutes);
+    temperature_minutes
+}
+
+/// Default weight of each new reading in a station's smoothed air temperature
+pub const DEFAULT_SMOOTHING_ALPHA: f32 = 0.2;
+
+/// Fold an optional reading into a running exponential moving average weighted by `alpha`,
+/// seeding the average with the reading if there is none yet
+pub(crate) fn smooth(average: Option<f32>, value: Option<f32>, alpha: f32) -> Option<f32> {
+    exponential_moving_average(average.iter().chain(value.iter()), alpha)
+}
+
+/// Computes the exponential moving average of readings ordered oldest to newest
+///
+/// The first reading seeds the average and each subsequent reading is weighted by `alpha`,
+/// which is clamped to the range 0.0 to 1.0. Returns None if there are no readings.
+pub fn exponential_moving_average<'a>(
+    samples: impl IntoIterator<Item = &'a f32>,
+    alpha: f32,
+) -> Option<f32> {
+    let alpha = alpha.clamp(0.0, 1.0);
+
+    samples.into_iter().fold(None, |average, &sample| {
+        Some(match average {
+            Some(average) => alpha * sample + (1.0 - alpha) * average,
+            None => sample,
+        })
+    })
+}
 
 /// Weather event types
-#[derive(Debug, Clone)]
+///
+/// Serializes to the JSON shape of the event's UDP packet.
+///
+/// New variants may be added in minor releases as event types are supported, so matches outside this crate
+/// need a wildcard arm. Until a type is supported its events are shared as `EventType::Unknown`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+#[non_exhaustive]
 pub enum EventType {
     Rain(RainStartEvent),
     Lightning(LightningStrikeEvent),
@@ -14,6 +173,438 @@ pub enum EventType {
     Sky(ObservationSkyEvent),
     DeviceStatus(DeviceStatusEvent),
     HubStatus(HubStatusEvent),
+    /// Event of a type this crate does not support, such as one added by new firmware, with its type tag and raw JSON
+    #[serde(serialize_with = "serialize_unknown")]
+    Unknown {
+        event_type: String,
+        raw: Value,
+    },
+}
+
+/// Serializes an unknown event as the raw JSON it was received as
+fn serialize_unknown<S: Serializer>(
+    _event_type: &String,
+    raw: &Value,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    raw.serialize(serializer)
+}
+
+/// Canonical form of a serial number, trimmed of surrounding whitespace and in upper case, e.g. "ST-00000512"
+///
+/// Proxies between a hub and the listener occasionally alter the serial numbers of events, so the listener
+/// normalizes them as events arrive and `Tempest` normalizes the serial numbers it is asked to look up.
+pub fn normalize_serial_number(serial_number: &str) -> String {
+    serial_number.trim().to_uppercase()
+}
+
+/// Kinds of WeatherFlow device, as encoded in the prefix of their serial numbers
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DeviceKind {
+    /// Tempest, serial numbers starting with `ST-`
+    Tempest,
+    /// Air, serial numbers starting with `AR-`
+    Air,
+    /// Sky, serial numbers starting with `SK-`
+    Sky,
+    /// Hub, serial numbers starting with `HB-`
+    Hub,
+}
+
+/// Classify a device by the prefix of its serial number, compared in its normalized form
+///
+/// Returns None for serial numbers without a known prefix
+pub fn device_kind_from_serial(sn: &str) -> Option<DeviceKind> {
+    let serial_number = normalize_serial_number(sn);
+
+    match serial_number.split_once('-')?.0 {
+        "ST" => Some(DeviceKind::Tempest),
+        "AR" => Some(DeviceKind::Air),
+        "SK" => Some(DeviceKind::Sky),
+        "HB" => Some(DeviceKind::Hub),
+        _ => None,
+    }
+}
+
+/// Columns of the flattened record produced by `EventType::to_record`
+pub const RECORD_COLUMNS: [&str; 29] = [
+    "event_type",
+    "serial_number",
+    "hub_sn",
+    "timestamp",
+    "firmware_revision",
+    "air_temperature",
+    "relative_humidity",
+    "station_pressure",
+    "wind_lull",
+    "wind_avg",
+    "wind_gust",
+    "wind_direction",
+    "wind_sample_interval",
+    "wind_speed",
+    "illuminance",
+    "uv",
+    "solar_radiation",
+    "rain_amount_prev_minute",
+    "local_day_rain_accum",
+    "precipitation_type",
+    "lightning_strike_count",
+    "lightning_strike_avg_distance",
+    "strike_distance",
+    "strike_energy",
+    "battery_voltage",
+    "report_interval",
+    "uptime",
+    "rssi",
+    "hub_rssi",
+];
+
+/// Parse a raw UDP payload into a weather event, independent of any socket
+///
+/// Returns an `EventError::ParseError` if the payload is not JSON or does not match the layout of its event type,
+/// and an `EventError::UnknownEventType` if its event type is missing or not supported by this crate.
+pub fn parse_event(bytes: &[u8]) -> Result<EventType, EventError> {
+    let json: Value = serde_json::from_slice(bytes).map_err(|e| {
+        debug!("Failed to deserialize packet contents into serde JSON value: {e}");
+        EventError::ParseError
+    })?;
+
+    EventType::from_json(&json)
+}
+
+impl TryFrom<&[u8]> for EventType {
+    type Error = EventError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        parse_event(bytes)
+    }
+}
+
+/// Type tags of the events supported by this crate
+pub const EVENT_TYPE_TAGS: [&str; 8] = [
+    "obs_st",
+    "obs_air",
+    "obs_sky",
+    "hub_status",
+    "rapid_wind",
+    "evt_precip",
+    "evt_strike",
+    "device_status",
+];
+
+/// Deserializes the event from its `type` tag, which each event also keeps as a field
+///
+/// This is why a `#[serde(tag = "type")]` derive is not used, as it consumes the tag before the event sees it.
+/// Events with an unsupported tag deserialize to `EventType::Unknown`, so an unknown event round trips through
+/// its serialized form.
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let json = Value::deserialize(deserializer)?;
+
+        match EventType::from_tagged(&json) {
+            Some(event) => event.map_err(de::Error::custom),
+            None => match json["type"].as_str() {
+                Some(event_type) => Ok(EventType::Unknown {
+                    event_type: event_type.to_string(),
+                    raw: json,
+                }),
+                None => Err(de::Error::missing_field("type")),
+            },
+        }
+    }
+}
+
+impl EventType {
+    /// Parse a JSON value into a weather event based on its `type` tag
+    ///
+    /// Returns an `EventError::ParseError` if the value does not match the layout of its event type,
+    /// and an `EventError::UnknownEventType` if its event type is missing or not supported by this crate.
+    pub fn from_json(json: &Value) -> Result<Self, EventError> {
+        match EventType::from_tagged(json) {
+            Some(event) => event.map_err(|e| {
+                debug!("Failed to deserialize event: {e}");
+                EventError::ParseError
+            }),
+            None => Err(EventError::UnknownEventType),
+        }
+    }
+
+    /// Deserialize a JSON value into the event its `type` tag names, None if the tag is missing or unsupported
+    fn from_tagged(json: &Value) -> Option<Result<Self, serde_json::Error>> {
+        let event = match json["type"].as_str()? {
+            "obs_st" => ObservationEvent::deserialize(json).map(EventType::Observation),
+            "obs_air" => ObservationAirEvent::deserialize(json).map(EventType::Air),
+            "obs_sky" => ObservationSkyEvent::deserialize(json).map(EventType::Sky),
+            "hub_status" => HubStatusEvent::deserialize(json).map(EventType::HubStatus),
+            "rapid_wind" => RapidWindEvent::deserialize(json).map(EventType::RapidWind),
+            "evt_precip" => RainStartEvent::deserialize(json).map(EventType::Rain),
+            "evt_strike" => LightningStrikeEvent::deserialize(json).map(EventType::Lightning),
+            "device_status" => DeviceStatusEvent::deserialize(json).map(EventType::DeviceStatus),
+            _ => return None,
+        };
+
+        Some(event)
+    }
+
+    /// Serial number of the device that sent the event
+    pub fn get_serial_number(&self) -> String {
+        match self {
+            EventType::Rain(event) => event.get_serial_number(),
+            EventType::Lightning(event) => event.get_serial_number(),
+            EventType::RapidWind(event) => event.get_serial_number(),
+            EventType::Observation(event) => event.get_serial_number(),
+            EventType::Air(event) => event.get_serial_number(),
+            EventType::Sky(event) => event.get_serial_number(),
+            EventType::DeviceStatus(event) => event.get_serial_number(),
+            EventType::HubStatus(event) => event.get_serial_number(),
+            EventType::Unknown { raw, .. } => {
+                normalize_serial_number(raw["serial_number"].as_str().unwrap_or_default())
+            }
+        }
+    }
+
+    /// Serial number of the hub the event was reported through, or of the hub itself for a hub status event
+    pub fn get_hub_sn(&self) -> String {
+        match self {
+            EventType::Rain(event) => event.get_hub_sn(),
+            EventType::Lightning(event) => event.get_hub_sn(),
+            EventType::RapidWind(event) => event.get_hub_sn(),
+            EventType::Observation(event) => event.get_hub_sn(),
+            EventType::Air(event) => event.get_hub_sn(),
+            EventType::Sky(event) => event.get_hub_sn(),
+            EventType::DeviceStatus(event) => event.get_hub_sn(),
+            EventType::HubStatus(event) => event.get_serial_number(),
+            EventType::Unknown { raw, .. } => {
+                normalize_serial_number(raw["hub_sn"].as_str().unwrap_or_default())
+            }
+        }
+    }
+
+    /// Normalize the event's serial number, and the serial number of the hub it was reported through, with
+    /// `normalize_serial_number`
+    ///
+    /// The raw JSON of an unknown event is left as received, its serial numbers being normalized when read instead.
+    pub fn normalize_serial_numbers(&mut self) {
+        let (serial_number, hub_sn) = match self {
+            EventType::Rain(event) => (&mut event.serial_number, Some(&mut event.hub_sn)),
+            EventType::Lightning(event) => (&mut event.serial_number, Some(&mut event.hub_sn)),
+            EventType::RapidWind(event) => (&mut event.serial_number, Some(&mut event.hub_sn)),
+            EventType::Observation(event) => (&mut event.serial_number, Some(&mut event.hub_sn)),
+            EventType::Air(event) => (&mut event.serial_number, Some(&mut event.hub_sn)),
+            EventType::Sky(event) => (&mut event.serial_number, Some(&mut event.hub_sn)),
+            EventType::DeviceStatus(event) => (&mut event.serial_number, Some(&mut event.hub_sn)),
+            EventType::HubStatus(event) => (&mut event.serial_number, None),
+            EventType::Unknown { .. } => return,
+        };
+
+        *serial_number = normalize_serial_number(serial_number);
+
+        if let Some(hub_sn) = hub_sn {
+            *hub_sn = normalize_serial_number(hub_sn);
+        }
+    }
+
+    /// Check the event's values are within their expected ranges, see `validate` of each event type
+    pub fn validate(&self) -> Result<(), EventError> {
+        match self {
+            EventType::Rain(event) => event.validate(),
+            EventType::Lightning(event) => event.validate(),
+            EventType::RapidWind(event) => event.validate(),
+            EventType::Observation(event) => event.validate(),
+            EventType::Air(event) => event.validate(),
+            EventType::Sky(event) => event.validate(),
+            EventType::DeviceStatus(event) => event.validate(),
+            EventType::HubStatus(event) => event.validate(),
+            EventType::Unknown { .. } => Ok(()),
+        }
+    }
+
+    /// Flatten the event into a single row keyed by every column in `RECORD_COLUMNS`
+    ///
+    /// Columns the event type does not report, or that could not be read, are `Value::Null` so that records of
+    /// different event types can be collected into a single table.
+    pub fn to_record(&self) -> BTreeMap<String, Value> {
+        let fields: Vec<(&str, Value)> = match self {
+            EventType::Rain(event) => vec![
+                ("event_type", event.r#type.clone().into()),
+                ("serial_number", event.get_serial_number().into()),
+                ("hub_sn", event.get_hub_sn().into()),
+                ("timestamp", event.try_get_timestamp().ok().into()),
+            ],
+            EventType::Lightning(event) => vec![
+                ("event_type", event.r#type.clone().into()),
+                ("serial_number", event.get_serial_number().into()),
+                ("hub_sn", event.get_hub_sn().into()),
+                ("timestamp", event.try_get_timestamp().ok().into()),
+                (
+                    "strike_distance",
+                    event.try_get_strike_distance().ok().into(),
+                ),
+                ("strike_energy", event.try_get_strike_energy().ok().into()),
+            ],
+            EventType::RapidWind(event) => vec![
+                ("event_type", event.r#type.clone().into()),
+                ("serial_number", event.get_serial_number().into()),
+                ("hub_sn", event.get_hub_sn().into()),
+                ("timestamp", event.get_timestamp_checked().ok().into()),
+                ("wind_speed", event.get_wind_speed_mps().into()),
+                ("wind_direction", event.get_wind_direction().into()),
+            ],
+            EventType::Observation(event) => vec![
+                ("event_type", event.r#type.clone().into()),
+                ("serial_number", event.get_serial_number().into()),
+                ("hub_sn", event.get_hub_sn().into()),
+                ("timestamp", event.get_timestamp_u64().ok().into()),
+                ("firmware_revision", event.get_firmware_revision().into()),
+                ("air_temperature", event.get_air_temperature().ok().into()),
+                ("relative_humidity", event.get_rh().ok().into()),
+                ("station_pressure", event.get_station_pressure().ok().into()),
+                ("wind_lull", event.get_wind_lull().ok().into()),
+                ("wind_avg", event.get_wind_avg().ok().into()),
+                ("wind_gust", event.get_wind_gust().ok().into()),
+                ("wind_direction", event.get_wind_direction().ok().into()),
+                (
+                    "wind_sample_interval",
+                    event.get_wind_sample_interval().ok().into(),
+                ),
+                ("illuminance", event.get_illuminance().ok().into()),
+                ("uv", event.get_uv().ok().into()),
+                ("solar_radiation", event.get_solar_radiation().ok().into()),
+                (
+                    "rain_amount_prev_minute",
+                    event.get_rain_amount_prev_min().ok().into(),
+                ),
+                (
+                    "precipitation_type",
+                    precipitation_type_value(event.get_precip_type()),
+                ),
+                (
+                    "lightning_strike_count",
+                    event.get_lightning_strike_count().ok().into(),
+                ),
+                (
+                    "lightning_strike_avg_distance",
+                    event.get_lightning_avg_distance().ok().into(),
+                ),
+                ("battery_voltage", event.get_battery_voltage().ok().into()),
+                ("report_interval", event.get_report_interval().ok().into()),
+            ],
+            EventType::Air(event) => vec![
+                ("event_type", event.r#type.clone().into()),
+                ("serial_number", event.get_serial_number().into()),
+                ("hub_sn", event.get_hub_sn().into()),
+                ("timestamp", event.get_timestamp_u64().ok().into()),
+                ("firmware_revision", event.get_firmware_revision().into()),
+                ("air_temperature", event.get_air_temperature().ok().into()),
+                (
+                    "relative_humidity",
+                    event.get_relative_humidity().ok().into(),
+                ),
+                ("station_pressure", event.get_station_pressure().ok().into()),
+                (
+                    "lightning_strike_count",
+                    event.get_lightning_count().ok().into(),
+                ),
+                (
+                    "lightning_strike_avg_distance",
+                    event.get_lightning_avg_distance().ok().into(),
+                ),
+                ("battery_voltage", event.get_battery_voltage().ok().into()),
+                ("report_interval", event.get_report_interval().ok().into()),
+            ],
+            EventType::Sky(event) => vec![
+                ("event_type", event.r#type.clone().into()),
+                ("serial_number", event.get_serial_number().into()),
+                ("hub_sn", event.get_hub_sn().into()),
+                ("timestamp", event.get_timestamp_u64().ok().into()),
+                ("firmware_revision", event.get_firmware_revision().into()),
+                ("wind_lull", event.get_wind_lull().ok().flatten().into()),
+                ("wind_avg", event.get_wind_avg().ok().flatten().into()),
+                ("wind_gust", event.get_wind_gust().ok().flatten().into()),
+                (
+                    "wind_direction",
+                    event.get_wind_direction().ok().flatten().into(),
+                ),
+                (
+                    "wind_sample_interval",
+                    event.get_wind_sample_interval().ok().flatten().into(),
+                ),
+                ("illuminance", event.get_illuminance().ok().flatten().into()),
+                ("uv", event.get_uv().ok().flatten().into()),
+                (
+                    "solar_radiation",
+                    event.get_solar_radiation().ok().flatten().into(),
+                ),
+                (
+                    "rain_amount_prev_minute",
+                    event.get_rain_prev_min().ok().flatten().into(),
+                ),
+                (
+                    "local_day_rain_accum",
+                    event.get_local_day_rain_accum().ok().flatten().into(),
+                ),
+                (
+                    "precipitation_type",
+                    precipitation_type_value(event.get_precip_type()),
+                ),
+                (
+                    "battery_voltage",
+                    event.get_battery_voltage().ok().flatten().into(),
+                ),
+                (
+                    "report_interval",
+                    event.get_report_interval().ok().flatten().into(),
+                ),
+            ],
+            EventType::DeviceStatus(event) => vec![
+                ("event_type", event.r#type.clone().into()),
+                ("serial_number", event.get_serial_number().into()),
+                ("hub_sn", event.get_hub_sn().into()),
+                ("timestamp", event.get_timestamp().into()),
+                ("firmware_revision", event.get_firmware_revision().into()),
+                ("battery_voltage", event.get_battery_voltage().into()),
+                ("uptime", event.get_uptime().into()),
+                ("rssi", event.get_rssi().into()),
+                ("hub_rssi", event.get_hub_rssi().into()),
+            ],
+            EventType::HubStatus(event) => vec![
+                ("event_type", event.r#type.clone().into()),
+                ("serial_number", event.get_serial_number().into()),
+                ("timestamp", event.get_timestamp().into()),
+                // hubs report their firmware revision as a string, parsed to match the station column
+                (
+                    "firmware_revision",
+                    event.get_firmware_revision().parse::<u16>().ok().into(),
+                ),
+                ("uptime", event.get_uptime().into()),
+                ("rssi", event.get_rssi().into()),
+            ],
+            EventType::Unknown { event_type, .. } => vec![
+                ("event_type", event_type.clone().into()),
+                ("serial_number", self.get_serial_number().into()),
+                ("hub_sn", self.get_hub_sn().into()),
+            ],
+        };
+
+        let mut record: BTreeMap<String, Value> = RECORD_COLUMNS
+            .iter()
+            .map(|column| (column.to_string(), Value::Null))
+            .collect();
+
+        for (column, value) in fields {
+            record.insert(column.to_string(), value);
+        }
+
+        record
+    }
+}
+
+/// Precipitation type as its serialized tag, or null if it could not be read
+fn precipitation_type_value(precipitation_type: Result<PrecipitationType, EventError>) -> Value {
+    precipitation_type
+        .ok()
+        .and_then(|precipitation_type| serde_json::to_value(precipitation_type).ok())
+        .unwrap_or(Value::Null)
 }
 
 impl From<HubStatusEvent> for Hub {
@@ -32,22 +623,19 @@ impl From<HubStatusEvent> for Hub {
                 version: *evt.radio_stats.first().unwrap_or(&0),
                 reboot_count: *evt.radio_stats.get(1).unwrap_or(&0),
                 i2c_bus_error_count: *evt.radio_stats.get(2).unwrap_or(&0),
-                radio_status: match *evt.radio_stats.get(3).unwrap_or(&0) {
-                    1 => RadioStatus::RadioOn,
-                    2 => RadioStatus::RadioActive,
-                    3 => RadioStatus::RadioActive,
-                    7 => RadioStatus::BLEConnected,
-                    _ => RadioStatus::RadioOff,
-                },
+                radio_status: radio_status_from_u16(*evt.radio_stats.get(3).unwrap_or(&0)),
                 radio_network_id: *evt.radio_stats.get(4).unwrap_or(&0),
             },
             mqtt_stats: evt.mqtt_stats,
+            previous_reboot_count: None,
+            first_seen: None,
+            last_seen: None,
         }
     }
 }
 
 /// General cached hub related information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Hub {
     pub serial_number: String,
     pub firmware_revision: String,
@@ -59,6 +647,12 @@ pub struct Hub {
     pub fs: Option<Vec<u32>>,
     pub radio_stats: RadioStats,
     pub mqtt_stats: Vec<u8>,
+    // radio reboot count of the previous hub status
+    pub previous_reboot_count: Option<u16>,
+    // local time the hub was first added to the cache
+    pub first_seen: Option<SystemTime>,
+    // local time the hub's most recent status was received
+    pub last_seen: Option<SystemTime>,
 }
 
 impl fmt::Display for Hub {
@@ -80,8 +674,94 @@ impl fmt::Display for Hub {
     }
 }
 
+impl Hub {
+    /// Returns the reset flags as `ResetFlag`s, skipping unknown codes
+    pub fn reset_causes(&self) -> Vec<ResetFlag> {
+        reset_flags_from_codes(self.reset_flags.iter().map(String::as_str))
+    }
+
+    /// Whether the radio is on and has not rebooted since the previous hub status
+    pub fn radio_healthy(&self) -> bool {
+        let powered = !matches!(
+            self.radio_stats.radio_status,
+            RadioStatus::RadioOff | RadioStatus::Unknown
+        );
+        let rebooted = self
+            .previous_reboot_count
+            .is_some_and(|previous| self.radio_stats.reboot_count > previous);
+
+        powered && !rebooted
+    }
+}
+
+/// Average and extremes of a reading across several stations
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricSummary {
+    pub average: f32,
+    pub min: f32,
+    pub max: f32,
+    pub count: usize,
+}
+
+impl MetricSummary {
+    /// Summarise the provided readings, returning None if there are none
+    pub fn from_values(values: impl IntoIterator<Item = f32>) -> Option<Self> {
+        let values: Vec<f32> = values.into_iter().collect();
+
+        if values.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            average: values.iter().sum::<f32>() / values.len() as f32,
+            min: values.iter().copied().fold(f32::INFINITY, f32::min),
+            max: values.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+            count: values.len(),
+        })
+    }
+}
+
+/// Summary of the most recent readings of every cached station reporting through a hub
+///
+/// Stations missing a reading are left out of that reading's summary, which is None if no station has it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HubAggregate {
+    pub hub_sn: String,
+    pub station_count: usize,
+    pub air_temperature: Option<MetricSummary>,
+    pub wind_avg: Option<MetricSummary>,
+    pub wind_gust: Option<MetricSummary>,
+    pub station_pressure: Option<MetricSummary>,
+}
+
+impl HubAggregate {
+    /// Summarise the readings of the provided stations reporting through the hub
+    pub fn from_stations(hub_sn: &str, stations: &[Station]) -> Self {
+        Self {
+            hub_sn: hub_sn.to_string(),
+            station_count: stations.len(),
+            air_temperature: MetricSummary::from_values(
+                stations
+                    .iter()
+                    .filter_map(|station| station.air_temperature),
+            ),
+            wind_avg: MetricSummary::from_values(
+                stations.iter().filter_map(|station| station.wind_avg),
+            ),
+            wind_gust: MetricSummary::from_values(
+                stations.iter().filter_map(|station| station.wind_gust),
+            ),
+            station_pressure: MetricSummary::from_values(
+                stations
+                    .iter()
+                    .filter_map(|station| station.station_pressure),
+            ),
+        }
+    }
+}
+
 /// General cached hub information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Station {
     // general station info
     pub hub_sn: String,
@@ -104,6 +784,34 @@ pub struct Station {
     pub wind_direction: Option<f32>,
     pub solar_radiation: Option<f32>,
     pub precipitation_type: Option<PrecipitationType>,
+    pub local_day_rain_accum: Option<f32>,
+    // recent readings
+    pub air_temperature_samples: VecDeque<f32>,
+    pub station_pressure_samples: VecDeque<f32>,
+    pub relative_humidity_samples: VecDeque<f32>,
+    // exponential moving average of every air temperature reading (C, celsius)
+    pub air_temperature_smoothed: Option<f32>,
+    pub lightning_history: VecDeque<LightningStrikeEvent>,
+    // rapid wind timestamps and speeds (m/s)
+    pub wind_history: VecDeque<(u64, f32)>,
+    // consecutive rapid wind readings matching the most recent speed and direction
+    pub identical_wind_readings: u32,
+    // minutes observed at each temperature in hundredths of a degree celsius
+    pub temperature_minutes: BTreeMap<i32, f32>,
+    // coldest and hottest apparent temperature seen
+    pub feels_like_extremes: Option<(f32, f32)>,
+    // rain (mm) summed over the observations since the accumulation was last reset
+    pub rain_accumulated: f32,
+    // timestamp of the newest observation counted towards the rain accumulation
+    pub last_rain_timestamp: Option<u64>,
+    // arrival timing
+    pub last_observation_received: Option<SystemTime>,
+    pub observation_arrival_interval: Option<f32>,
+    pub last_seen: Option<SystemTime>,
+    // local time the station was first added to the cache
+    pub first_seen: Option<SystemTime>,
+    // seconds the station's event timestamps lead local time by
+    pub clock_skew: Option<i64>,
     // events
     pub observation: Option<ObservationEvent>,
     pub wind_event: Option<RapidWindEvent>,
@@ -114,6 +822,354 @@ pub struct Station {
     pub device_status: Option<DeviceStatusEvent>,
 }
 
+impl Station {
+    /// Returns true if the other record is of the same device, regardless of the readings cached for each
+    pub fn same_device(&self, other: &Station) -> bool {
+        self.serial_number == other.serial_number
+    }
+
+    /// Merge another record of the same station into this one
+    ///
+    /// Readings and events from whichever record was seen most recently take precedence, with any gaps filled
+    /// from the other record. Recent readings, lightning history and temperature minutes are combined.
+    pub fn merge(&mut self, other: Station) {
+        let older = if other.last_seen > self.last_seen {
+            std::mem::replace(self, other)
+        } else {
+            other
+        };
+
+        if self.hub_sn.is_empty() {
+            self.hub_sn = older.hub_sn;
+        }
+
+        // general station info
+        self.firmware_revision = self.firmware_revision.or(older.firmware_revision);
+        self.battery_voltage = self.battery_voltage.or(older.battery_voltage);
+
+        // common weather data
+        self.air_temperature = self.air_temperature.or(older.air_temperature);
+        self.station_pressure = self.station_pressure.or(older.station_pressure);
+        self.relative_humidity = self.relative_humidity.or(older.relative_humidity);
+        self.lightning_strike_count = self.lightning_strike_count.or(older.lightning_strike_count);
+        self.lightning_strike_avg_distance = self
+            .lightning_strike_avg_distance
+            .or(older.lightning_strike_avg_distance);
+        self.illuminance = self.illuminance.or(older.illuminance);
+        self.uv = self.uv.or(older.uv);
+        self.rain_amount_prev_minute = self
+            .rain_amount_prev_minute
+            .or(older.rain_amount_prev_minute);
+        self.prev_rain_timestamp = self.prev_rain_timestamp.or(older.prev_rain_timestamp);
+        self.wind_lull = self.wind_lull.or(older.wind_lull);
+        self.wind_avg = self.wind_avg.or(older.wind_avg);
+        self.wind_gust = self.wind_gust.or(older.wind_gust);
+        self.wind_direction = self.wind_direction.or(older.wind_direction);
+        self.solar_radiation = self.solar_radiation.or(older.solar_radiation);
+        self.precipitation_type = self.precipitation_type.take().or(older.precipitation_type);
+        self.local_day_rain_accum = self.local_day_rain_accum.or(older.local_day_rain_accum);
+
+        // recent readings, the older record's first
+        for (samples, older_samples) in [
+            (
+                &mut self.air_temperature_samples,
+                older.air_temperature_samples,
+            ),
+            (
+                &mut self.station_pressure_samples,
+                older.station_pressure_samples,
+            ),
+            (
+                &mut self.relative_humidity_samples,
+                older.relative_humidity_samples,
+            ),
+        ] {
+            let newer_samples = std::mem::take(samples);
+
+            for value in older_samples.into_iter().chain(newer_samples) {
+                push_sample(samples, Some(value));
+            }
+        }
+
+        self.air_temperature_smoothed = self
+            .air_temperature_smoothed
+            .or(older.air_temperature_smoothed);
+
+        let mut strikes: Vec<LightningStrikeEvent> = older
+            .lightning_history
+            .into_iter()
+            .chain(std::mem::take(&mut self.lightning_history))
+            .collect();
+        strikes.sort_by_key(|strike| strike.try_get_timestamp().unwrap_or_default());
+        strikes.dedup();
+
+        for strike in strikes {
+            push_strike(&mut self.lightning_history, strike);
+        }
+
+        // keep as many samples as the longer history retained
+        let wind_capacity = WIND_HISTORY_CAPACITY
+            .max(self.wind_history.len())
+            .max(older.wind_history.len());
+        let mut winds: Vec<(u64, f32)> = older
+            .wind_history
+            .into_iter()
+            .chain(std::mem::take(&mut self.wind_history))
+            .collect();
+        winds.sort_by_key(|&(timestamp, _)| timestamp);
+        winds.dedup_by_key(|&mut (timestamp, _)| timestamp);
+
+        for (timestamp, speed) in winds {
+            push_wind(&mut self.wind_history, wind_capacity, timestamp, speed);
+        }
+
+        for (temperature, minutes) in older.temperature_minutes {
+            *self.temperature_minutes.entry(temperature).or_insert(0.0) += minutes;
+        }
+
+        self.feels_like_extremes = match (self.feels_like_extremes, older.feels_like_extremes) {
+            (Some((coldest, hottest)), Some((older_coldest, older_hottest))) => {
+                Some((coldest.min(older_coldest), hottest.max(older_hottest)))
+            }
+            (extremes, older_extremes) => extremes.or(older_extremes),
+        };
+
+        self.rain_accumulated += older.rain_accumulated;
+        self.last_rain_timestamp = self.last_rain_timestamp.max(older.last_rain_timestamp);
+        self.first_seen = self.first_seen.into_iter().chain(older.first_seen).min();
+
+        // arrival timing
+        self.last_observation_received = self
+            .last_observation_received
+            .max(older.last_observation_received);
+        self.observation_arrival_interval = self
+            .observation_arrival_interval
+            .or(older.observation_arrival_interval);
+        self.clock_skew = self.clock_skew.or(older.clock_skew);
+
+        // events
+        self.observation = self.observation.take().or(older.observation);
+        self.wind_event = self.wind_event.take().or(older.wind_event);
+        self.rain_event = self.rain_event.take().or(older.rain_event);
+        self.lightning_event = self.lightning_event.take().or(older.lightning_event);
+        self.air_event = self.air_event.take().or(older.air_event);
+        self.sky_event = self.sky_event.take().or(older.sky_event);
+        self.device_status = self.device_status.take().or(older.device_status);
+    }
+
+    /// Most recent reading of a metric
+    pub fn latest(&self, metric: SampledMetric) -> Option<f32> {
+        match metric {
+            SampledMetric::AirTemperature => self.air_temperature,
+            SampledMetric::StationPressure => self.station_pressure,
+            SampledMetric::RelativeHumidity => self.relative_humidity,
+        }
+    }
+
+    /// Recent readings of a metric, oldest first
+    pub fn samples(&self, metric: SampledMetric) -> &VecDeque<f32> {
+        match metric {
+            SampledMetric::AirTemperature => &self.air_temperature_samples,
+            SampledMetric::StationPressure => &self.station_pressure_samples,
+            SampledMetric::RelativeHumidity => &self.relative_humidity_samples,
+        }
+    }
+
+    /// Most recent air temperature (F, fahrenheit)
+    pub fn air_temperature_f(&self) -> Option<f32> {
+        self.air_temperature.map(celsius_to_fahrenheit)
+    }
+
+    /// Most recent average wind speed (mph, miles per hour)
+    pub fn wind_avg_mph(&self) -> Option<f32> {
+        self.wind_avg.map(mps_to_mph)
+    }
+
+    /// Most recent wind gust speed (mph, miles per hour)
+    pub fn wind_gust_mph(&self) -> Option<f32> {
+        self.wind_gust.map(mps_to_mph)
+    }
+
+    /// Most recent station pressure (inHg, inches of mercury)
+    pub fn station_pressure_inhg(&self) -> Option<f32> {
+        self.station_pressure.map(mb_to_inhg)
+    }
+
+    /// Most recent average lightning strike distance (mi, miles) reported by an observation
+    ///
+    /// See `last_strike_distance_miles` for the distance of the most recent strike event
+    pub fn lightning_distance_miles(&self) -> Option<f32> {
+        self.lightning_strike_avg_distance.map(km_to_miles)
+    }
+
+    /// Distance (mi, miles) of the most recent lightning strike event
+    pub fn last_strike_distance_miles(&self) -> Option<f32> {
+        self.lightning_event
+            .as_ref()?
+            .try_get_strike_distance()
+            .ok()
+            .map(|distance| km_to_miles(distance as f32))
+    }
+
+    /// Most recent wind speed average (m/s, meters per second) from whichever of the observation, sky observation or
+    /// rapid wind samples is newest, see `freshest_wind`
+    pub fn freshest_wind_avg(&self) -> Option<f32> {
+        self.freshest_wind(
+            |observation| observation.get_wind_avg().ok(),
+            |sky| sky.get_wind_avg().ok().flatten(),
+            |speeds| Some(speeds.iter().sum::<f32>() / speeds.len() as f32),
+        )
+    }
+
+    /// Most recent wind speed gust (m/s, meters per second) from whichever of the observation, sky observation or
+    /// rapid wind samples is newest, see `freshest_wind`
+    pub fn freshest_wind_gust(&self) -> Option<f32> {
+        self.freshest_wind(
+            |observation| observation.get_wind_gust().ok(),
+            |sky| sky.get_wind_gust().ok().flatten(),
+            |speeds| speeds.iter().copied().reduce(f32::max),
+        )
+    }
+
+    /// Read a wind value from whichever source reporting it has the newest timestamp
+    ///
+    /// Rapid wind samples are summarised over the minute up to the most recent sample, matching the observation
+    /// report interval.
+    fn freshest_wind(
+        &self,
+        observed: impl Fn(&ObservationEvent) -> Option<f32>,
+        sky: impl Fn(&ObservationSkyEvent) -> Option<f32>,
+        rapid: impl Fn(&[f32]) -> Option<f32>,
+    ) -> Option<f32> {
+        let observation = self
+            .observation
+            .as_ref()
+            .and_then(|event| Some((event.timestamp_systemtime().ok()?, observed(event)?)));
+
+        let sky = self
+            .sky_event
+            .as_ref()
+            .and_then(|event| Some((event.timestamp_systemtime().ok()?, sky(event)?)));
+
+        let rapid = self.wind_history.back().and_then(|&(latest, _)| {
+            let speeds: Vec<f32> = self
+                .wind_history
+                .iter()
+                .filter(|(timestamp, _)| latest.saturating_sub(*timestamp) < 60)
+                .map(|&(_, speed)| speed)
+                .collect();
+
+            Some((epoch_seconds_to_systemtime(latest), rapid(&speeds)?))
+        });
+
+        [observation, sky, rapid]
+            .into_iter()
+            .flatten()
+            .max_by_key(|&(time, _)| time)
+            .map(|(_, value)| value)
+    }
+
+    /// Kind of device based on the prefix of the station's serial number, see `device_kind_from_serial`
+    pub fn device_kind(&self) -> Option<DeviceKind> {
+        device_kind_from_serial(&self.serial_number)
+    }
+
+    /// Whether the most recent precipitation type reports any precipitation, None if no type has been reported
+    pub fn is_precipitating(&self) -> Option<bool> {
+        self.precipitation_type
+            .as_ref()
+            .map(PrecipitationType::is_precipitating)
+    }
+
+    /// Most recent wind direction as a point of the 16-point compass rose, e.g. "NNE"
+    pub fn wind_direction_cardinal(&self) -> Option<&'static str> {
+        self.wind_direction.map(cardinal_direction)
+    }
+
+    /// Apparent temperature (C, celsius) from the most recent air temperature, relative humidity and average wind speed
+    ///
+    /// Above `HEAT_INDEX_TEMPERATURE` this is the `heat_index`, below `WIND_CHILL_TEMPERATURE` it is the `wind_chill`,
+    /// and otherwise it is the air temperature.
+    ///
+    /// Returns None if the air temperature, or the humidity or wind speed the applicable formula needs, is unknown
+    pub fn feels_like(&self) -> Option<f32> {
+        let temperature = self.air_temperature?;
+
+        if temperature > HEAT_INDEX_TEMPERATURE {
+            Some(heat_index(temperature, self.relative_humidity?))
+        } else if temperature < WIND_CHILL_TEMPERATURE {
+            Some(wind_chill(temperature, self.wind_avg?))
+        } else {
+            Some(temperature)
+        }
+    }
+
+    /// Widen the apparent temperature extremes to include the current apparent temperature, if known
+    pub(crate) fn record_feels_like(&mut self) {
+        if let Some(feels_like) = self.feels_like() {
+            self.feels_like_extremes = Some(match self.feels_like_extremes {
+                Some((coldest, hottest)) => (coldest.min(feels_like), hottest.max(feels_like)),
+                None => (feels_like, feels_like),
+            });
+        }
+    }
+
+    /// Whether the most recent air temperature is at or below freezing
+    ///
+    /// Returns None if the air temperature is unknown
+    pub fn is_freezing(&self) -> Option<bool> {
+        self.air_temperature.map(|temperature| temperature <= 0.0)
+    }
+
+    /// Estimate the risk of frost from the most recent air temperature, relative humidity and solar radiation
+    ///
+    /// Frost is likely when the air is freezing, or when it is at or below `FROST_LIKELY_TEMPERATURE` with the
+    /// dew point within `FROST_DEW_POINT_SPREAD` under dark skies (solar radiation below `NIGHT_SOLAR_RADIATION`),
+    /// where radiative cooling takes surfaces below the air temperature. Frost is possible at or below
+    /// `FROST_POSSIBLE_TEMPERATURE`. A missing solar radiation reading is treated as dark.
+    ///
+    /// Returns None if the air temperature or relative humidity is unknown
+    pub fn frost_risk(&self) -> Option<FrostRisk> {
+        let temperature = self.air_temperature?;
+        let dew_point = magnus_dew_point(temperature, self.relative_humidity?);
+        let dark = self
+            .solar_radiation
+            .is_none_or(|radiation| radiation < NIGHT_SOLAR_RADIATION);
+
+        if temperature <= 0.0
+            || (temperature <= FROST_LIKELY_TEMPERATURE
+                && temperature - dew_point <= FROST_DEW_POINT_SPREAD
+                && dark)
+        {
+            Some(FrostRisk::Likely)
+        } else if temperature <= FROST_POSSIBLE_TEMPERATURE {
+            Some(FrostRisk::Possible)
+        } else {
+            Some(FrostRisk::None)
+        }
+    }
+
+    /// Accumulated degree minutes (C·min) spent above the provided base temperature (C, celsius)
+    pub fn cooling_degree_minutes(&self, base_c: f32) -> f32 {
+        self.temperature_minutes
+            .iter()
+            .map(|(&temperature, &minutes)| {
+                (temperature as f32 / 100.0 - base_c).max(0.0) * minutes
+            })
+            .sum()
+    }
+
+    /// Accumulated degree minutes (C·min) spent below the provided base temperature (C, celsius)
+    pub fn heating_degree_minutes(&self, base_c: f32) -> f32 {
+        self.temperature_minutes
+            .iter()
+            .map(|(&temperature, &minutes)| {
+                (base_c - temperature as f32 / 100.0).max(0.0) * minutes
+            })
+            .sum()
+    }
+}
+
 impl From<ObservationEvent> for Station {
     /// Retuns a `Station` created from an `ObservationEvent`
     fn from(event: ObservationEvent) -> Self {
@@ -127,18 +1183,40 @@ impl From<ObservationEvent> for Station {
             air_temperature: event.get_air_temperature().ok(),
             station_pressure: event.get_station_pressure().ok(),
             relative_humidity: event.get_rh().ok(),
-            lightning_strike_count: event.get_lightning_avg_distance().ok(),
-            lightning_strike_avg_distance: event.get_lightning_strike_count().ok(),
+            lightning_strike_count: event.get_lightning_strike_count().ok(),
+            lightning_strike_avg_distance: event.get_lightning_avg_distance().ok(),
             illuminance: event.get_illuminance().ok(),
             uv: event.get_uv().ok(),
             rain_amount_prev_minute: event.get_rain_amount_prev_min().ok(),
             prev_rain_timestamp: None,
             wind_lull: event.get_wind_lull().ok(),
-            wind_avg: event.get_wind_gust().ok(),
+            wind_avg: event.get_wind_avg().ok(),
             wind_gust: event.get_wind_gust().ok(),
-            wind_direction: event.get_solar_radiation().ok(),
+            wind_direction: event.get_wind_direction().ok(),
             solar_radiation: event.get_solar_radiation().ok(),
             precipitation_type: event.get_precip_type().ok(),
+            local_day_rain_accum: None,
+            // recent readings
+            air_temperature_samples: initial_samples(event.get_air_temperature().ok()),
+            station_pressure_samples: initial_samples(event.get_station_pressure().ok()),
+            relative_humidity_samples: initial_samples(event.get_rh().ok()),
+            air_temperature_smoothed: event.get_air_temperature().ok(),
+            lightning_history: VecDeque::new(),
+            wind_history: VecDeque::new(),
+            identical_wind_readings: 0,
+            feels_like_extremes: None,
+            rain_accumulated: 0.0,
+            last_rain_timestamp: None,
+            temperature_minutes: initial_temperature_minutes(
+                event.get_air_temperature().ok(),
+                event.get_report_interval().ok(),
+            ),
+            // arrival timing
+            last_observation_received: None,
+            observation_arrival_interval: None,
+            last_seen: None,
+            first_seen: None,
+            clock_skew: None,
             // events
             observation: Some(event),
             wind_event: None,
@@ -176,6 +1254,28 @@ impl From<RapidWindEvent> for Station {
             wind_direction: None,
             solar_radiation: None,
             precipitation_type: None,
+            local_day_rain_accum: None,
+            // recent readings
+            air_temperature_samples: VecDeque::new(),
+            station_pressure_samples: VecDeque::new(),
+            relative_humidity_samples: VecDeque::new(),
+            air_temperature_smoothed: None,
+            lightning_history: VecDeque::new(),
+            wind_history: event
+                .get_timestamp_checked()
+                .map(|timestamp| VecDeque::from([(timestamp, event.get_wind_speed_mps())]))
+                .unwrap_or_default(),
+            identical_wind_readings: 1,
+            feels_like_extremes: None,
+            rain_accumulated: 0.0,
+            last_rain_timestamp: None,
+            temperature_minutes: BTreeMap::new(),
+            // arrival timing
+            last_observation_received: None,
+            observation_arrival_interval: None,
+            last_seen: None,
+            first_seen: None,
+            clock_skew: None,
             // events
             observation: None,
             wind_event: Some(event),
@@ -193,7 +1293,7 @@ impl From<RainStartEvent> for Station {
     fn from(event: RainStartEvent) -> Self {
         Self {
             // general station info
-            hub_sn: event.get_serial_number(),
+            hub_sn: event.get_hub_sn(),
             firmware_revision: None,
             serial_number: event.get_serial_number(),
             battery_voltage: None,
@@ -206,13 +1306,32 @@ impl From<RainStartEvent> for Station {
             illuminance: None,
             uv: None,
             rain_amount_prev_minute: None,
-            prev_rain_timestamp: Some(event.get_timestamp()),
+            prev_rain_timestamp: event.try_get_timestamp().ok(),
             wind_lull: None,
             wind_avg: None,
             wind_gust: None,
             wind_direction: None,
             solar_radiation: None,
             precipitation_type: None,
+            local_day_rain_accum: None,
+            // recent readings
+            air_temperature_samples: VecDeque::new(),
+            station_pressure_samples: VecDeque::new(),
+            relative_humidity_samples: VecDeque::new(),
+            air_temperature_smoothed: None,
+            lightning_history: VecDeque::new(),
+            wind_history: VecDeque::new(),
+            identical_wind_readings: 0,
+            feels_like_extremes: None,
+            rain_accumulated: 0.0,
+            last_rain_timestamp: None,
+            temperature_minutes: BTreeMap::new(),
+            // arrival timing
+            last_observation_received: None,
+            observation_arrival_interval: None,
+            last_seen: None,
+            first_seen: None,
+            clock_skew: None,
             // events
             observation: None,
             wind_event: None,
@@ -250,6 +1369,25 @@ impl From<LightningStrikeEvent> for Station {
             wind_direction: None,
             solar_radiation: None,
             precipitation_type: None,
+            local_day_rain_accum: None,
+            // recent readings
+            air_temperature_samples: VecDeque::new(),
+            station_pressure_samples: VecDeque::new(),
+            relative_humidity_samples: VecDeque::new(),
+            air_temperature_smoothed: None,
+            lightning_history: VecDeque::from([event.clone()]),
+            wind_history: VecDeque::new(),
+            identical_wind_readings: 0,
+            feels_like_extremes: None,
+            rain_accumulated: 0.0,
+            last_rain_timestamp: None,
+            temperature_minutes: BTreeMap::new(),
+            // arrival timing
+            last_observation_received: None,
+            observation_arrival_interval: None,
+            last_seen: None,
+            first_seen: None,
+            clock_skew: None,
             // events
             observation: None,
             wind_event: None,
@@ -287,6 +1425,28 @@ impl From<ObservationAirEvent> for Station {
             wind_direction: None,
             solar_radiation: None,
             precipitation_type: None,
+            local_day_rain_accum: None,
+            // recent readings
+            air_temperature_samples: initial_samples(event.get_air_temperature().ok()),
+            station_pressure_samples: initial_samples(event.get_station_pressure().ok()),
+            relative_humidity_samples: initial_samples(event.get_relative_humidity().ok()),
+            air_temperature_smoothed: event.get_air_temperature().ok(),
+            lightning_history: VecDeque::new(),
+            wind_history: VecDeque::new(),
+            identical_wind_readings: 0,
+            feels_like_extremes: None,
+            rain_accumulated: 0.0,
+            last_rain_timestamp: None,
+            temperature_minutes: initial_temperature_minutes(
+                event.get_air_temperature().ok(),
+                event.get_report_interval().ok(),
+            ),
+            // arrival timing
+            last_observation_received: None,
+            observation_arrival_interval: None,
+            last_seen: None,
+            first_seen: None,
+            clock_skew: None,
             // events
             observation: None,
             wind_event: None,
@@ -324,6 +1484,25 @@ impl From<ObservationSkyEvent> for Station {
             wind_direction: event.get_wind_direction().ok().unwrap_or_default(),
             solar_radiation: event.get_solar_radiation().ok().unwrap_or_default(),
             precipitation_type: event.get_precip_type().ok(),
+            local_day_rain_accum: event.get_local_day_rain_accum().ok().flatten(),
+            // recent readings
+            air_temperature_samples: VecDeque::new(),
+            station_pressure_samples: VecDeque::new(),
+            relative_humidity_samples: VecDeque::new(),
+            air_temperature_smoothed: None,
+            lightning_history: VecDeque::new(),
+            wind_history: VecDeque::new(),
+            identical_wind_readings: 0,
+            feels_like_extremes: None,
+            rain_accumulated: 0.0,
+            last_rain_timestamp: None,
+            temperature_minutes: BTreeMap::new(),
+            // arrival timing
+            last_observation_received: None,
+            observation_arrival_interval: None,
+            last_seen: None,
+            first_seen: None,
+            clock_skew: None,
             // events
             observation: None,
             wind_event: None,
@@ -361,6 +1540,25 @@ impl From<DeviceStatusEvent> for Station {
             wind_direction: None,
             solar_radiation: None,
             precipitation_type: None,
+            local_day_rain_accum: None,
+            // recent readings
+            air_temperature_samples: VecDeque::new(),
+            station_pressure_samples: VecDeque::new(),
+            relative_humidity_samples: VecDeque::new(),
+            air_temperature_smoothed: None,
+            lightning_history: VecDeque::new(),
+            wind_history: VecDeque::new(),
+            identical_wind_readings: 0,
+            feels_like_extremes: None,
+            rain_accumulated: 0.0,
+            last_rain_timestamp: None,
+            temperature_minutes: BTreeMap::new(),
+            // arrival timing
+            last_observation_received: None,
+            observation_arrival_interval: None,
+            last_seen: None,
+            first_seen: None,
+            clock_skew: None,
             // events
             observation: None,
             wind_event: None,
@@ -374,7 +1572,7 @@ impl From<DeviceStatusEvent> for Station {
 }
 
 /// Preciptation types
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PrecipitationType {
     None,
     Rain,
@@ -382,6 +1580,13 @@ pub enum PrecipitationType {
     RainHail, /* Experimental */
 }
 
+impl PrecipitationType {
+    /// Returns true for any precipitation, being false only for `PrecipitationType::None`
+    pub fn is_precipitating(&self) -> bool {
+        *self != PrecipitationType::None
+    }
+}
+
 impl fmt::Display for PrecipitationType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -397,62 +1602,473 @@ impl fmt::Display for PrecipitationType {
     }
 }
 
-/// Radio statuses
+/// Average wind speed (m/s) below which the wind is considered calm
+pub const CALM_WIND_SPEED: f32 = 0.5;
+
+/// Ratio of gust to average wind speed at or above which the wind is considered gusty
+pub const GUSTY_WIND_FACTOR: f32 = 1.5;
+
+/// Minimum difference (m/s) between gust and average wind speed for the wind to be considered gusty
+pub const GUSTY_WIND_SPREAD: f32 = 2.5;
+
+/// Wind regimes classified from average wind speed and gusts
 #[derive(Debug, Clone, PartialEq)]
-pub enum RadioStatus {
-    RadioOff,
-    RadioOn,
-    RadioActive,
-    BLEConnected,
-    Unknown,
+pub enum WindRegime {
+    Calm,
+    Steady,
+    Gusty,
 }
 
-impl fmt::Display for RadioStatus {
+impl WindRegime {
+    /// Classify the wind from its average and gust speeds (m/s)
+    ///
+    /// Wind is calm when the average is below `CALM_WIND_SPEED` and gusty when the gust factor
+    /// reaches `GUSTY_WIND_FACTOR` with the gust exceeding the average by at least `GUSTY_WIND_SPREAD`.
+    pub fn classify(wind_avg: f32, wind_gust: f32) -> Self {
+        if wind_avg < CALM_WIND_SPEED {
+            WindRegime::Calm
+        } else if wind_gust / wind_avg >= GUSTY_WIND_FACTOR
+            && wind_gust - wind_avg >= GUSTY_WIND_SPREAD
+        {
+            WindRegime::Gusty
+        } else {
+            WindRegime::Steady
+        }
+    }
+}
+
+impl fmt::Display for WindRegime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "{}",
             match self {
-                RadioStatus::RadioOff => "Radio Off",
-                RadioStatus::RadioOn => "Radio On",
-                RadioStatus::RadioActive => "Radio Active",
-                RadioStatus::BLEConnected => "BLE Connected",
-                RadioStatus::Unknown => "Unknown",
+                WindRegime::Calm => "Calm",
+                WindRegime::Steady => "Steady",
+                WindRegime::Gusty => "Gusty",
             }
         )
     }
 }
 
-/// Event error codes
-#[derive(Debug, PartialEq)]
-pub enum EventError {
-    ParseError,
-    UnexpectedValue,
+/// Points of the 16-point compass rose, clockwise from north
+pub const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
+/// Maps a direction (degrees) to the nearest point of the 16-point compass rose
+///
+/// Each point covers 22.5° centered on its heading, so 348.75° up to 11.25° maps to "N".
+/// Directions outside 0-360° wrap around.
+pub fn cardinal_direction(degrees: f32) -> &'static str {
+    let sector = (degrees.rem_euclid(360.0) / 22.5 + 0.5).floor() as usize;
+
+    COMPASS_POINTS[sector % COMPASS_POINTS.len()]
 }
 
-/// Rain start event for a station
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct RainStartEvent {
-    serial_number: String,
-    r#type: String,
-    hub_sn: String,
-    evt: Vec<u64>,
+/// Converts the true wind into the apparent wind felt on a moving vessel or vehicle
+///
+/// Wind directions are the direction the wind blows from and the vessel heading is the direction it travels
+/// towards, all in degrees clockwise from north. Speeds share whichever unit is provided, such as the m/s of a
+/// `RapidWindEvent`. Returns the apparent wind speed and direction, with the direction relative to north
+/// rather than the vessel's bow.
+///
+/// Each wind is treated as a vector pointing towards where it blows from, `speed * (sin(dir), cos(dir))`.
+/// Moving through still air creates a wind blowing from the heading at the vessel's speed, so the apparent
+/// wind is the sum of the true wind and the vessel's motion.
+pub fn true_to_apparent_wind(
+    true_speed: f32,
+    true_direction: f32,
+    vessel_speed: f32,
+    vessel_heading: f32,
+) -> (f32, f32) {
+    combine_winds(true_speed, true_direction, vessel_speed, vessel_heading)
 }
 
-impl fmt::Display for RainStartEvent {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "RainStartEvent Data (Timestamp: {}, Serial Number: {}, Hub Serial Number: {})",
-            self.get_timestamp(),
-            self.get_serial_number(),
-            self.get_hub_sn(),
-        )
-    }
+/// Converts the apparent wind measured on a moving vessel or vehicle into the true wind
+///
+/// The inverse of `true_to_apparent_wind`, using the same units and angle conventions: the true wind is the
+/// apparent wind with the vessel's motion removed. Returns the true wind speed and direction.
+pub fn apparent_to_true_wind(
+    apparent_speed: f32,
+    apparent_direction: f32,
+    vessel_speed: f32,
+    vessel_heading: f32,
+) -> (f32, f32) {
+    combine_winds(
+        apparent_speed,
+        apparent_direction,
+        -vessel_speed,
+        vessel_heading,
+    )
 }
 
-impl RainStartEvent {
-    pub fn get_serial_number(&self) -> String {
+/// Sum two winds given as speed and the direction they blow from, returning the resulting speed and direction
+fn combine_winds(speed: f32, direction: f32, other_speed: f32, other_direction: f32) -> (f32, f32) {
+    let (sin, cos) = direction.to_radians().sin_cos();
+    let (other_sin, other_cos) = other_direction.to_radians().sin_cos();
+
+    let x = speed * sin + other_speed * other_sin;
+    let y = speed * cos + other_speed * other_cos;
+
+    (x.hypot(y), x.atan2(y).to_degrees().rem_euclid(360.0))
+}
+
+/// Offset between degrees celsius and kelvin
+pub const KELVIN_OFFSET: f32 = 273.15;
+
+/// Magnus coefficient (MB, millibars) for saturation vapour pressure over water
+const MAGNUS_A: f32 = 6.112;
+
+/// Magnus coefficient for saturation vapour pressure over water
+const MAGNUS_B: f32 = 17.62;
+
+/// Magnus coefficient (C, celsius) for saturation vapour pressure over water
+const MAGNUS_C: f32 = 243.12;
+
+/// Computes the dew point (C, celsius) from air temperature (C, celsius) and relative humidity (%) with the Magnus formula
+pub(crate) fn magnus_dew_point(air_temperature: f32, relative_humidity: f32) -> f32 {
+    let gamma = (relative_humidity / 100.0).ln()
+        + MAGNUS_B * air_temperature / (MAGNUS_C + air_temperature);
+
+    MAGNUS_C * gamma / (MAGNUS_B - gamma)
+}
+
+/// Ratio of the molecular weights of water vapour and dry air
+pub const MOLECULAR_WEIGHT_RATIO: f32 = 0.622;
+
+/// Computes the vapour pressure (MB, millibars) from air temperature (C, celsius) and relative humidity (%) with the Magnus formula
+fn vapour_pressure(air_temperature: f32, relative_humidity: f32) -> f32 {
+    relative_humidity / 100.0
+        * MAGNUS_A
+        * (MAGNUS_B * air_temperature / (MAGNUS_C + air_temperature)).exp()
+}
+
+/// Computes the mixing ratio (g/kg), the mass of water vapour per mass of dry air, from air temperature (C, celsius),
+/// relative humidity (%) and station pressure (MB, millibars)
+pub fn mixing_ratio(air_temperature: f32, relative_humidity: f32, station_pressure: f32) -> f32 {
+    let vapour_pressure = vapour_pressure(air_temperature, relative_humidity);
+
+    1000.0 * MOLECULAR_WEIGHT_RATIO * vapour_pressure / (station_pressure - vapour_pressure)
+}
+
+/// Computes the specific humidity (g/kg), the mass of water vapour per mass of moist air, from air temperature (C, celsius),
+/// relative humidity (%) and station pressure (MB, millibars)
+pub fn specific_humidity(
+    air_temperature: f32,
+    relative_humidity: f32,
+    station_pressure: f32,
+) -> f32 {
+    let vapour_pressure = vapour_pressure(air_temperature, relative_humidity);
+
+    1000.0 * MOLECULAR_WEIGHT_RATIO * vapour_pressure
+        / (station_pressure - (1.0 - MOLECULAR_WEIGHT_RATIO) * vapour_pressure)
+}
+
+/// Air temperature (C, celsius) above which the feels like temperature is the heat index
+pub const HEAT_INDEX_TEMPERATURE: f32 = 27.0;
+
+/// Air temperature (C, celsius) below which the feels like temperature is the wind chill
+pub const WIND_CHILL_TEMPERATURE: f32 = 10.0;
+
+/// Wind speed (km/h) at or below which wind chill is not defined
+pub const WIND_CHILL_MIN_SPEED_KMH: f32 = 4.8;
+
+/// Computes the NOAA heat index (C, celsius) from air temperature (C, celsius) and relative humidity (%)
+///
+/// Uses the Steadman approximation, switching to the Rothfusz regression (with the NWS adjustments for very dry
+/// and very humid air) once the approximation averaged with the air temperature reaches 80°F.
+pub fn heat_index(air_temperature: f32, relative_humidity: f32) -> f32 {
+    let t = celsius_to_fahrenheit(air_temperature);
+    let rh = relative_humidity;
+
+    let mut index = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+
+    if (index + t) / 2.0 >= 80.0 {
+        index = -42.379 + 2.049_015_2 * t + 10.143_331 * rh
+            - 0.224_755_4 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh;
+
+        if rh < 13.0 && (80.0..=112.0).contains(&t) {
+            index -= (13.0 - rh) / 4.0 * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt();
+        } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+            index += (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0);
+        }
+    }
+
+    fahrenheit_to_celsius(index)
+}
+
+/// Computes the wind chill (C, celsius) from air temperature (C, celsius) and wind speed (m/s)
+///
+/// Uses the joint NWS and Environment Canada formula, returning the air temperature unchanged when the
+/// wind is at or below `WIND_CHILL_MIN_SPEED_KMH`.
+pub fn wind_chill(air_temperature: f32, wind_speed: f32) -> f32 {
+    let speed = wind_speed * 3.6;
+
+    if speed <= WIND_CHILL_MIN_SPEED_KMH {
+        return air_temperature;
+    }
+
+    let factor = speed.powf(0.16);
+
+    13.12 + 0.6215 * air_temperature - 11.37 * factor + 0.3965 * air_temperature * factor
+}
+
+/// Air temperature (C, celsius) at or below which frost is likely on a clear night near saturation
+pub const FROST_LIKELY_TEMPERATURE: f32 = 2.0;
+
+/// Air temperature (C, celsius) at or below which frost is possible
+pub const FROST_POSSIBLE_TEMPERATURE: f32 = 4.0;
+
+/// Difference (C, celsius) between air temperature and dew point within which the air is considered near saturation
+pub const FROST_DEW_POINT_SPREAD: f32 = 2.0;
+
+/// Solar radiation (W/m^2) below which skies are considered dark enough for radiative cooling
+pub const NIGHT_SOLAR_RADIATION: f32 = 5.0;
+
+/// Photosynthetically active photons (µmol) per joule of daylight solar radiation
+///
+/// Roughly 45% of daylight shortwave energy falls in the 400-700nm band at about 4.57 µmol/J, giving 2.02 µmol/J.
+pub const PAR_PER_SOLAR_RADIATION: f32 = 2.02;
+
+/// Approximates photosynthetically active radiation (µmol·m⁻²·s⁻¹) from solar radiation (W/m^2)
+///
+/// The fixed daylight conversion factor suits natural sunlight, the ratio varies with cloud cover and sun angle so
+/// expect errors of around 10%.
+pub fn solar_radiation_to_par(solar_radiation: f32) -> f32 {
+    solar_radiation * PAR_PER_SOLAR_RADIATION
+}
+
+/// Risk of frost forming
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrostRisk {
+    None,
+    Possible,
+    Likely,
+}
+
+impl fmt::Display for FrostRisk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FrostRisk::None => "None",
+                FrostRisk::Possible => "Possible",
+                FrostRisk::Likely => "Likely",
+            }
+        )
+    }
+}
+
+/// Onset or cessation of precipitation at a station
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrecipTransition {
+    Started { serial_number: String },
+    Stopped { serial_number: String },
+}
+
+impl fmt::Display for PrecipTransition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrecipTransition::Started { serial_number } => {
+                write!(f, "Precipitation started at {serial_number}")
+            }
+            PrecipTransition::Stopped { serial_number } => {
+                write!(f, "Precipitation stopped at {serial_number}")
+            }
+        }
+    }
+}
+
+/// Reference pressure (MB, millibars) for potential temperature
+pub const REFERENCE_PRESSURE_MB: f32 = 1000.0;
+
+/// Poisson exponent (R/cp) for dry air
+pub const POISSON_EXPONENT: f32 = 0.286;
+
+/// Computes the potential temperature (C, celsius) from air temperature (C, celsius) and station pressure (MB, millibars)
+///
+/// The potential temperature is the temperature air would reach if brought adiabatically to `REFERENCE_PRESSURE_MB`,
+/// θ = T * (P0 / P)^(R/cp) with temperatures in kelvin, converted back to celsius.
+pub fn potential_temperature(air_temperature: f32, station_pressure: f32) -> f32 {
+    (air_temperature + KELVIN_OFFSET)
+        * (REFERENCE_PRESSURE_MB / station_pressure).powf(POISSON_EXPONENT)
+        - KELVIN_OFFSET
+}
+
+/// Check that a reading is a finite value within the inclusive range
+fn check_range(value: f32, min: f32, max: f32) -> Result<(), EventError> {
+    if value.is_finite() && (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        debug!("Reading {value} outside of expected range {min} to {max}");
+        Err(EventError::UnexpectedValue)
+    }
+}
+
+/// Check that a reading, when present, is a finite value within the inclusive range
+fn check_optional_range(value: Option<f32>, min: f32, max: f32) -> Result<(), EventError> {
+    value.map_or(Ok(()), |value| check_range(value, min, max))
+}
+
+/// Upper bound (km) of the visibility estimate
+pub const MAX_VISIBILITY_KM: f32 = 10.0;
+
+/// Relative humidity (%) at which fog is assumed to begin reducing visibility
+pub const FOG_ONSET_RELATIVE_HUMIDITY: f32 = 90.0;
+
+/// Visibility (km) assumed at saturation (100% relative humidity)
+pub const SATURATED_VISIBILITY_KM: f32 = 0.2;
+
+/// Radio statuses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RadioStatus {
+    RadioOff,
+    RadioOn,
+    RadioActive,
+    BLEConnected,
+    Unknown,
+}
+
+impl fmt::Display for RadioStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                RadioStatus::RadioOff => "Radio Off",
+                RadioStatus::RadioOn => "Radio On",
+                RadioStatus::RadioActive => "Radio Active",
+                RadioStatus::BLEConnected => "BLE Connected",
+                RadioStatus::Unknown => "Unknown",
+            }
+        )
+    }
+}
+
+/// Causes of the most recent hub reset, reported as comma separated codes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResetFlag {
+    /// `BOR`, brownout reset
+    BrownOut,
+    /// `PIN`, reset pin asserted
+    PinReset,
+    /// `POR`, power on reset
+    PowerOn,
+    /// `SFT`, software reset
+    SoftwareReset,
+    /// `WDG`, watchdog reset
+    WatchdogReset,
+    /// `WWD`, window watchdog reset
+    WindowWatchdog,
+    /// `LPW`, low power reset
+    LowPower,
+}
+
+impl ResetFlag {
+    /// Returns the reset flag for a code, or `None` if the code is not recognised
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "BOR" => Some(ResetFlag::BrownOut),
+            "PIN" => Some(ResetFlag::PinReset),
+            "POR" => Some(ResetFlag::PowerOn),
+            "SFT" => Some(ResetFlag::SoftwareReset),
+            "WDG" => Some(ResetFlag::WatchdogReset),
+            "WWD" => Some(ResetFlag::WindowWatchdog),
+            "LPW" => Some(ResetFlag::LowPower),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ResetFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ResetFlag::BrownOut => "Brownout",
+                ResetFlag::PinReset => "Pin Reset",
+                ResetFlag::PowerOn => "Power On",
+                ResetFlag::SoftwareReset => "Software Reset",
+                ResetFlag::WatchdogReset => "Watchdog Reset",
+                ResetFlag::WindowWatchdog => "Window Watchdog",
+                ResetFlag::LowPower => "Low Power",
+            }
+        )
+    }
+}
+
+/// Reset flags from their codes, skipping any that are not recognised
+fn reset_flags_from_codes<'a>(codes: impl IntoIterator<Item = &'a str>) -> Vec<ResetFlag> {
+    codes
+        .into_iter()
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+        .filter_map(|code| {
+            let flag = ResetFlag::from_code(code);
+
+            if flag.is_none() {
+                warn!("Skipping unknown reset flag {code:?}");
+            }
+
+            flag
+        })
+        .collect()
+}
+
+/// Time of an epoch timestamp in whole seconds
+fn epoch_seconds_to_systemtime(seconds: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)
+}
+
+/// Event error codes
+#[derive(Debug, PartialEq)]
+pub enum EventError {
+    ParseError,
+    UnexpectedValue,
+    UnknownEventType,
+}
+
+/// Error returned when a value cannot be retrieved from the cache
+#[derive(Debug, PartialEq)]
+pub enum LookupError {
+    /// The station is not present in the cache
+    StationNotFound,
+    /// The station is cached but has not reported the value
+    FieldUnavailable,
+}
+
+/// Rain start event for a station
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RainStartEvent {
+    serial_number: String,
+    r#type: String,
+    hub_sn: String,
+    evt: Vec<u64>,
+}
+
+impl fmt::Display for RainStartEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RainStartEvent Data (Timestamp: {}, Serial Number: {}, Hub Serial Number: {})",
+            self.try_get_timestamp().unwrap_or(0),
+            self.get_serial_number(),
+            self.get_hub_sn(),
+        )
+    }
+}
+
+impl RainStartEvent {
+    pub fn get_serial_number(&self) -> String {
         self.serial_number.clone()
     }
 
@@ -460,9 +2076,43 @@ impl RainStartEvent {
         self.hub_sn.clone()
     }
 
+    #[deprecated(
+        since = "0.2.0",
+        note = "use `try_get_timestamp`, which does not panic on a malformed event"
+    )]
     pub fn get_timestamp(&self) -> u64 {
         self.evt[0]
     }
+
+    pub fn try_get_timestamp(&self) -> Result<u64, EventError> {
+        self.evt.first().copied().ok_or_else(|| {
+            debug!(
+                "Unable to retrieve timestamp from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })
+    }
+
+    /// Timestamp as a `SystemTime`
+    pub fn timestamp_systemtime(&self) -> Result<SystemTime, EventError> {
+        self.try_get_timestamp().map(epoch_seconds_to_systemtime)
+    }
+
+    /// Timestamp as a UTC date and time, see `timestamp_systemtime`
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_datetime(&self) -> Result<DateTime<Utc>, EventError> {
+        self.timestamp_systemtime().map(DateTime::from)
+    }
+
+    /// Check the event carries a timestamp
+    pub fn validate(&self) -> Result<(), EventError> {
+        if self.evt.is_empty() {
+            return Err(EventError::ParseError);
+        }
+
+        Ok(())
+    }
 }
 
 /// Lightning strike event for a station
@@ -479,11 +2129,11 @@ impl fmt::Display for LightningStrikeEvent {
         write!(
             f,
             "LightningStrikeEvent Data (Timestamp: {}, Serial Number: {}, Hub Serial Number: {}, Strike Distance: {} km, Energy: {})",
-            self.get_timestamp(),
+            self.try_get_timestamp().unwrap_or(0),
             self.get_serial_number(),
             self.get_hub_sn(),
-            self.get_strike_distance(),
-            self.get_strike_energy()
+            self.try_get_strike_distance().unwrap_or(0),
+            self.try_get_strike_energy().unwrap_or(0)
         )
     }
 }
@@ -497,17 +2147,79 @@ impl LightningStrikeEvent {
         self.hub_sn.clone()
     }
 
+    #[deprecated(
+        since = "0.2.0",
+        note = "use `try_get_timestamp`, which does not panic on a malformed event"
+    )]
     pub fn get_timestamp(&self) -> u64 {
         self.evt[0]
     }
 
+    #[deprecated(
+        since = "0.2.0",
+        note = "use `try_get_strike_distance`, which does not panic on a malformed event"
+    )]
     pub fn get_strike_distance(&self) -> u64 {
         self.evt[1]
     }
 
+    #[deprecated(
+        since = "0.2.0",
+        note = "use `try_get_strike_energy`, which does not panic on a malformed event"
+    )]
     pub fn get_strike_energy(&self) -> u64 {
         self.evt[2]
     }
+
+    pub fn try_get_timestamp(&self) -> Result<u64, EventError> {
+        self.evt.first().copied().ok_or_else(|| {
+            debug!(
+                "Unable to retrieve timestamp from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })
+    }
+
+    /// Timestamp as a `SystemTime`
+    pub fn timestamp_systemtime(&self) -> Result<SystemTime, EventError> {
+        self.try_get_timestamp().map(epoch_seconds_to_systemtime)
+    }
+
+    /// Timestamp as a UTC date and time, see `timestamp_systemtime`
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_datetime(&self) -> Result<DateTime<Utc>, EventError> {
+        self.timestamp_systemtime().map(DateTime::from)
+    }
+
+    pub fn try_get_strike_distance(&self) -> Result<u64, EventError> {
+        self.evt.get(1).copied().ok_or_else(|| {
+            debug!(
+                "Unable to retrieve strike distance from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })
+    }
+
+    pub fn try_get_strike_energy(&self) -> Result<u64, EventError> {
+        self.evt.get(2).copied().ok_or_else(|| {
+            debug!(
+                "Unable to retrieve strike energy from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })
+    }
+
+    /// Check the event carries a timestamp, strike distance and strike energy
+    pub fn validate(&self) -> Result<(), EventError> {
+        if self.evt.len() < 3 {
+            return Err(EventError::ParseError);
+        }
+
+        Ok(())
+    }
 }
 
 /// Rapid wind event for a station
@@ -524,7 +2236,7 @@ impl fmt::Display for RapidWindEvent {
         write!(
             f,
             "RapidWindEvent Data (Timestamp: {}, Serial Number: {}, Hub Serial Number: {}, Wind Speed: {} m/s, Wind Direction: {}°)",
-            self.get_timestamp(),
+            self.get_timestamp_checked().unwrap_or(0),
             self.get_serial_number(),
             self.get_hub_sn(),
             self.get_wind_speed_mps(),
@@ -541,16 +2253,61 @@ impl RapidWindEvent {
         self.hub_sn.clone()
     }
 
+    #[deprecated(
+        since = "0.2.0",
+        note = "use `get_timestamp_checked`, which does not panic on a malformed event"
+    )]
     pub fn get_timestamp(&self) -> u64 {
         self.ob[0] as u64
     }
 
+    pub fn get_timestamp_checked(&self) -> Result<u64, EventError> {
+        self.ob
+            .first()
+            .map(|&timestamp| timestamp as u64)
+            .ok_or_else(|| {
+                debug!(
+                    "Unable to retrieve timestamp from {}",
+                    std::any::type_name::<Self>()
+                );
+                EventError::ParseError
+            })
+    }
+
+    /// Timestamp as a `SystemTime`
+    pub fn timestamp_systemtime(&self) -> Result<SystemTime, EventError> {
+        self.get_timestamp_checked()
+            .map(epoch_seconds_to_systemtime)
+    }
+
+    /// Timestamp as a UTC date and time, see `timestamp_systemtime`
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_datetime(&self) -> Result<DateTime<Utc>, EventError> {
+        self.timestamp_systemtime().map(DateTime::from)
+    }
+
+    /// Wind speed (m/s), reading as calm (0.0) when the event omits it
     pub fn get_wind_speed_mps(&self) -> f32 {
-        self.ob[1] as f32
+        self.ob.get(1).map_or(0.0, |&speed| speed as f32)
     }
 
+    /// Wind direction (degrees), reading as calm (0) when the event omits it as some calm events do
     pub fn get_wind_direction(&self) -> u16 {
-        self.ob[2] as u16
+        self.ob.get(2).map_or(0, |&direction| direction as u16)
+    }
+
+    /// Check the event carries a timestamp and the wind speed and direction are finite and within their physical ranges
+    pub fn validate(&self) -> Result<(), EventError> {
+        if self.ob.is_empty() {
+            return Err(EventError::ParseError);
+        }
+
+        check_range(self.get_wind_speed_mps(), 0.0, 100.0)?;
+        check_range(
+            self.ob.get(2).map_or(0.0, |&direction| direction as f32),
+            0.0,
+            360.0,
+        )
     }
 }
 
@@ -560,7 +2317,7 @@ pub struct ObservationAirEvent {
     serial_number: String,
     r#type: String,
     hub_sn: String,
-    obs: Vec<Vec<f32>>,
+    obs: Vec<Vec<f64>>,
     firmware_revision: u16,
 }
 
@@ -569,7 +2326,7 @@ impl fmt::Display for ObservationAirEvent {
         write!(
             f,
             "ObservationAirEvent Data (Timestamp: {}, Serial Number: {}, Hub SN: {}, Firmware Revision: {}, Station Pressure: {}, Air Temperature: {}, Relative Humidity: {}%, Lightning Strike Count: {}, Lightning Strike Avg Distance: {} km, Battery Voltage: {}V, Report Interval: {})",
-            self.get_timestamp().unwrap_or(0.0),
+            self.get_timestamp_u64().unwrap_or(0),
             self.get_serial_number(),
             self.get_hub_sn(),
             self.get_firmware_revision(),
@@ -598,99 +2355,199 @@ impl ObservationAirEvent {
     }
 
     pub fn get_timestamp(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve timestamp from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[0];
+        let data = self
+            .obs
+            .first()
+            .and_then(|obs| obs.first().copied())
+            .ok_or_else(|| {
+                debug!(
+                    "Unable to retrieve timestamp from {}",
+                    std::any::type_name::<Self>()
+                );
+                EventError::ParseError
+            })?;
 
-        Ok(data)
+        Ok(data as f32)
     }
 
-    pub fn get_station_pressure(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve station pressure from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[1];
+    /// Retrieve the timestamp as whole epoch seconds without the rounding of an `f32`
+    pub fn get_timestamp_u64(&self) -> Result<u64, EventError> {
+        let data = self
+            .obs
+            .first()
+            .and_then(|obs| obs.first().copied())
+            .ok_or_else(|| {
+                debug!(
+                    "Unable to retrieve timestamp from {}",
+                    std::any::type_name::<Self>()
+                );
+                EventError::ParseError
+            })?;
 
-        Ok(data)
+        Ok(data as u64)
     }
 
-    pub fn get_air_temperature(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve air temperature from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[2];
-
-        Ok(data)
+    /// Timestamp as a `SystemTime`
+    pub fn timestamp_systemtime(&self) -> Result<SystemTime, EventError> {
+        self.get_timestamp_u64().map(epoch_seconds_to_systemtime)
     }
 
-    pub fn get_relative_humidity(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve relative humidity from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[3];
+    /// Timestamp as a UTC date and time, see `timestamp_systemtime`
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_datetime(&self) -> Result<DateTime<Utc>, EventError> {
+        self.timestamp_systemtime().map(DateTime::from)
+    }
 
-        Ok(data)
+    /// Positions of the readings within the observation row, based on the firmware revision and row length
+    pub fn get_field_layout(&self) -> AirFieldLayout {
+        AirFieldLayout::for_firmware(self.firmware_revision, self.obs.first().map_or(0, Vec::len))
     }
 
-    pub fn get_lightning_count(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve lightning strike count from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[4];
+    /// Retrieve a reading at a position of the layout, which is missing if the layout lacks the reading
+    fn get_reading(&self, index: Option<usize>, name: &str) -> Result<f32, EventError> {
+        let data = self
+            .obs
+            .first()
+            .zip(index)
+            .and_then(|(obs, index)| obs.get(index).copied())
+            .ok_or_else(|| {
+                debug!(
+                    "Unable to retrieve {name} from {}",
+                    std::any::type_name::<Self>()
+                );
+                EventError::ParseError
+            })?;
 
-        Ok(data)
+        Ok(data as f32)
     }
 
-    pub fn get_lightning_avg_distance(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve lightning avg distance from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[5];
+    pub fn get_station_pressure(&self) -> Result<f32, EventError> {
+        self.get_reading(
+            Some(self.get_field_layout().station_pressure),
+            "station pressure",
+        )
+    }
 
-        Ok(data)
+    pub fn get_air_temperature(&self) -> Result<f32, EventError> {
+        self.get_reading(
+            Some(self.get_field_layout().air_temperature),
+            "air temperature",
+        )
     }
 
-    pub fn get_battery_voltage(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve battery voltage from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[6];
+    pub fn get_relative_humidity(&self) -> Result<f32, EventError> {
+        self.get_reading(
+            Some(self.get_field_layout().relative_humidity),
+            "relative humidity",
+        )
+    }
+
+    pub fn get_lightning_count(&self) -> Result<f32, EventError> {
+        self.get_reading(
+            self.get_field_layout().lightning_count,
+            "lightning strike count",
+        )
+    }
+
+    pub fn get_lightning_avg_distance(&self) -> Result<f32, EventError> {
+        self.get_reading(
+            self.get_field_layout().lightning_avg_distance,
+            "lightning avg distance",
+        )
+    }
 
-        Ok(data)
+    pub fn get_battery_voltage(&self) -> Result<f32, EventError> {
+        self.get_reading(
+            Some(self.get_field_layout().battery_voltage),
+            "battery voltage",
+        )
     }
 
     pub fn get_report_interval(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve report interval from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[7];
+        self.get_reading(
+            Some(self.get_field_layout().report_interval),
+            "report interval",
+        )
+    }
+
+    /// Computes the dew point (C, celsius) from the air temperature and relative humidity with the Magnus-Tetens approximation
+    pub fn dew_point(&self) -> Result<f32, EventError> {
+        Ok(magnus_dew_point(
+            self.get_air_temperature()?,
+            self.get_relative_humidity()?,
+        ))
+    }
+
+    /// Check the readings are finite and within their physical ranges
+    pub fn validate(&self) -> Result<(), EventError> {
+        check_range(self.get_station_pressure()?, 250.0, 1100.0)?;
+        check_range(self.get_air_temperature()?, -100.0, 100.0)?;
+        check_range(self.get_relative_humidity()?, 0.0, 100.0)?;
+
+        // firmware omitting the lightning readings is not malformed
+        if self.get_field_layout().has_lightning() {
+            check_range(self.get_lightning_count()?, 0.0, f32::MAX)?;
+            check_range(self.get_lightning_avg_distance()?, 0.0, 100.0)?;
+        }
+
+        check_range(self.get_battery_voltage()?, 0.0, 5.0)
+    }
+}
+
+/// AIR firmware revision of the observation in the v171 UDP reference
+pub const AIR_V171_FIRMWARE_REVISION: u16 = 17;
+
+/// Positions of the readings within an AIR observation row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AirFieldLayout {
+    pub station_pressure: usize,
+    pub air_temperature: usize,
+    pub relative_humidity: usize,
+    pub lightning_count: Option<usize>,
+    pub lightning_avg_distance: Option<usize>,
+    pub battery_voltage: usize,
+    pub report_interval: usize,
+}
+
+impl AirFieldLayout {
+    /// Layout documented by the v171 UDP reference
+    pub const V171: Self = AirFieldLayout {
+        station_pressure: 1,
+        air_temperature: 2,
+        relative_humidity: 3,
+        lightning_count: Some(4),
+        lightning_avg_distance: Some(5),
+        battery_voltage: 6,
+        report_interval: 7,
+    };
+
+    /// Layout of firmware that omits the lightning readings, shifting the battery voltage and report interval down
+    pub const WITHOUT_LIGHTNING: Self = AirFieldLayout {
+        station_pressure: 1,
+        air_temperature: 2,
+        relative_humidity: 3,
+        lightning_count: None,
+        lightning_avg_distance: None,
+        battery_voltage: 4,
+        report_interval: 5,
+    };
+
+    /// Layout of a row of `row_length` readings from an AIR running `firmware_revision`, defaulting to the v171 layout
+    ///
+    /// The v171 reference documents a single layout, shown for firmware revision `AIR_V171_FIRMWARE_REVISION`.
+    /// Rows from later firmware that are two readings short of that layout are taken to have omitted the
+    /// lightning readings.
+    pub fn for_firmware(firmware_revision: u16, row_length: usize) -> Self {
+        if firmware_revision > AIR_V171_FIRMWARE_REVISION && row_length == 6 {
+            AirFieldLayout::WITHOUT_LIGHTNING
+        } else {
+            AirFieldLayout::V171
+        }
+    }
 
-        Ok(data)
+    /// Whether the layout carries the lightning readings
+    pub fn has_lightning(&self) -> bool {
+        self.lightning_count.is_some() && self.lightning_avg_distance.is_some()
     }
 }
 
@@ -700,7 +2557,7 @@ pub struct ObservationSkyEvent {
     serial_number: String,
     r#type: String,
     hub_sn: String,
-    obs: Vec<Vec<Option<f32>>>,
+    obs: Vec<Vec<Option<f64>>>,
     firmware_revision: u16,
 }
 
@@ -731,21 +2588,51 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_timestamp(&self) -> Result<Option<f32>, EventError> {
-        match self.obs.first() {
-            Some(obs) => Ok(obs[0]),
-            None => {
-                eprintln!(
+        self.reading(0).ok_or_else(|| {
+            debug!(
+                "Unable to retrieve timestamp from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })
+    }
+
+    /// Retrieve the timestamp as whole epoch seconds without the rounding of an `f32`
+    ///
+    /// Returns an `EventError::UnexpectedValue` if the timestamp is null
+    pub fn get_timestamp_u64(&self) -> Result<u64, EventError> {
+        let timestamp = self
+            .obs
+            .first()
+            .and_then(|obs| obs.first().copied())
+            .ok_or_else(|| {
+                debug!(
                     "Unable to retrieve timestamp from {}",
                     std::any::type_name::<Self>()
                 );
-                Err(EventError::ParseError)
-            }
-        }
+                EventError::ParseError
+            })?
+            .ok_or(EventError::UnexpectedValue)?;
+
+        Ok(timestamp as u64)
+    }
+
+    /// Timestamp as a `SystemTime`
+    ///
+    /// Returns an `EventError::UnexpectedValue` if the timestamp is null
+    pub fn timestamp_systemtime(&self) -> Result<SystemTime, EventError> {
+        self.get_timestamp_u64().map(epoch_seconds_to_systemtime)
+    }
+
+    /// Timestamp as a UTC date and time, see `timestamp_systemtime`
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_datetime(&self) -> Result<DateTime<Utc>, EventError> {
+        self.timestamp_systemtime().map(DateTime::from)
     }
 
     pub fn get_illuminance(&self) -> Result<Option<f32>, EventError> {
-        self.obs.first().and_then(|vec| vec.get(1).copied()).ok_or({
-            eprintln!(
+        self.reading(1).ok_or_else(|| {
+            debug!(
                 "Unable to retrieve illuminance from {}",
                 std::any::type_name::<Self>()
             );
@@ -754,8 +2641,8 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_uv(&self) -> Result<Option<f32>, EventError> {
-        self.obs.first().and_then(|vec| vec.get(2).copied()).ok_or({
-            eprintln!(
+        self.reading(2).ok_or_else(|| {
+            debug!(
                 "Unable to retrieve UV from {}",
                 std::any::type_name::<Self>()
             );
@@ -764,8 +2651,8 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_rain_prev_min(&self) -> Result<Option<f32>, EventError> {
-        self.obs.first().and_then(|vec| vec.get(3).copied()).ok_or({
-            eprintln!(
+        self.reading(3).ok_or_else(|| {
+            debug!(
                 "Unable to retrieve rain previous minute from {}",
                 std::any::type_name::<Self>()
             );
@@ -774,8 +2661,8 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_wind_lull(&self) -> Result<Option<f32>, EventError> {
-        self.obs.first().and_then(|vec| vec.get(4).copied()).ok_or({
-            eprintln!(
+        self.reading(4).ok_or_else(|| {
+            debug!(
                 "Unable to retrieve wind lull from {}",
                 std::any::type_name::<Self>()
             );
@@ -784,8 +2671,8 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_wind_avg(&self) -> Result<Option<f32>, EventError> {
-        self.obs.first().and_then(|vec| vec.get(5).copied()).ok_or({
-            eprintln!(
+        self.reading(5).ok_or_else(|| {
+            debug!(
                 "Unable to retrieve wind avg from {}",
                 std::any::type_name::<Self>()
             );
@@ -794,8 +2681,8 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_wind_gust(&self) -> Result<Option<f32>, EventError> {
-        self.obs.first().and_then(|vec| vec.get(6).copied()).ok_or({
-            eprintln!(
+        self.reading(6).ok_or_else(|| {
+            debug!(
                 "Unable to retrieve wind gust from {}",
                 std::any::type_name::<Self>()
             );
@@ -804,8 +2691,8 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_wind_direction(&self) -> Result<Option<f32>, EventError> {
-        self.obs.first().and_then(|vec| vec.get(7).copied()).ok_or({
-            eprintln!(
+        self.reading(7).ok_or_else(|| {
+            debug!(
                 "Unable to retrieve wind direction from {}",
                 std::any::type_name::<Self>()
             );
@@ -814,8 +2701,8 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_battery_voltage(&self) -> Result<Option<f32>, EventError> {
-        self.obs.first().and_then(|vec| vec.get(8).copied()).ok_or({
-            eprintln!(
+        self.reading(8).ok_or_else(|| {
+            debug!(
                 "Unable to retrieve battery voltage from {}",
                 std::any::type_name::<Self>()
             );
@@ -824,8 +2711,8 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_report_interval(&self) -> Result<Option<f32>, EventError> {
-        self.obs.first().and_then(|vec| vec.get(9).copied()).ok_or({
-            eprintln!(
+        self.reading(9).ok_or_else(|| {
+            debug!(
                 "Unable to retrieve report interval from {}",
                 std::any::type_name::<Self>()
             );
@@ -834,69 +2721,84 @@ impl ObservationSkyEvent {
     }
 
     pub fn get_solar_radiation(&self) -> Result<Option<f32>, EventError> {
-        self.obs
-            .first()
-            .and_then(|vec| vec.get(10).copied())
-            .ok_or({
-                eprintln!(
-                    "Unable to retrieve solar radiation from {}",
-                    std::any::type_name::<Self>()
-                );
-                EventError::ParseError
-            })
+        self.reading(10).ok_or_else(|| {
+            debug!(
+                "Unable to retrieve solar radiation from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })
+    }
+
+    /// Photosynthetically active radiation (µmol·m⁻²·s⁻¹) approximated from solar radiation,
+    /// see `solar_radiation_to_par`
+    pub fn get_par(&self) -> Result<Option<f32>, EventError> {
+        Ok(self.get_solar_radiation()?.map(solar_radiation_to_par))
     }
 
     pub fn get_local_day_rain_accum(&self) -> Result<Option<f32>, EventError> {
-        self.obs
-            .first()
-            .and_then(|vec| vec.get(11).copied())
-            .ok_or({
-                eprintln!(
-                    "Unable to retrieve local day rain accumulation from {}",
-                    std::any::type_name::<Self>()
-                );
-                EventError::ParseError
-            })
+        self.reading(11).ok_or_else(|| {
+            debug!(
+                "Unable to retrieve local day rain accumulation from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })
     }
 
+    /// Precipitation type, where a null reading means no precipitation was reported
+    ///
+    /// Returns an `EventError::ParseError` if the reading is absent from the observation
     pub fn get_precip_type(&self) -> Result<PrecipitationType, EventError> {
-        match self
-            .obs
-            .first()
-            .and_then(|vec| vec.get(12).copied())
-            .unwrap_or_default()
-        {
-            Some(precip) => match precip as u16 {
-                0 => Ok(PrecipitationType::None),
-                1 => Ok(PrecipitationType::Rain),
-                2 => Ok(PrecipitationType::Hail),
-                3 => Ok(PrecipitationType::RainHail),
-                _ => {
-                    eprintln!("Unknown precipitation type");
-                    Err(EventError::UnexpectedValue)
-                }
-            },
-            None => {
-                eprintln!(
-                    "Unable to retrieve precipitation type from {}",
-                    std::any::type_name::<Self>()
-                );
-                Err(EventError::ParseError)
+        let precip = self.reading(12).ok_or_else(|| {
+            debug!(
+                "Unable to retrieve precipitation type from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })?;
+
+        match precip.map(|precip| precip as u16) {
+            None | Some(0) => Ok(PrecipitationType::None),
+            Some(1) => Ok(PrecipitationType::Rain),
+            Some(2) => Ok(PrecipitationType::Hail),
+            Some(3) => Ok(PrecipitationType::RainHail),
+            Some(_) => {
+                debug!("Unknown precipitation type");
+                Err(EventError::UnexpectedValue)
             }
         }
     }
 
     pub fn get_wind_sample_interval(&self) -> Result<Option<f32>, EventError> {
-        match self.obs.first() {
-            Some(obs) => Ok(obs[13]),
-            None => {
-                eprintln!(
-                    "Unable to retrieve wind sample interval from {}",
-                    std::any::type_name::<Self>()
-                );
-                Err(EventError::ParseError)
-            }
-        }
+        self.reading(13).ok_or_else(|| {
+            debug!(
+                "Unable to retrieve wind sample interval from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })
+    }
+
+    /// Reading at an index of the first observation, None if the observation is too short to hold it
+    fn reading(&self, index: usize) -> Option<Option<f32>> {
+        self.obs
+            .first()
+            .and_then(|obs| obs.get(index).copied())
+            .map(|reading| reading.map(|reading| reading as f32))
+    }
+
+    /// Check the readings that are present are finite and within their physical ranges
+    pub fn validate(&self) -> Result<(), EventError> {
+        check_optional_range(self.get_illuminance()?, 0.0, 200_000.0)?;
+        check_optional_range(self.get_uv()?, 0.0, 25.0)?;
+        check_optional_range(self.get_rain_prev_min()?, 0.0, f32::MAX)?;
+        check_optional_range(self.get_wind_lull()?, 0.0, 100.0)?;
+        check_optional_range(self.get_wind_avg()?, 0.0, 100.0)?;
+        check_optional_range(self.get_wind_gust()?, 0.0, 100.0)?;
+        check_optional_range(self.get_wind_direction()?, 0.0, 360.0)?;
+        check_optional_range(self.get_battery_voltage()?, 0.0, 5.0)?;
+        check_optional_range(self.get_solar_radiation()?, 0.0, 2000.0)
     }
 }
 
@@ -906,7 +2808,7 @@ pub struct ObservationEvent {
     serial_number: String,
     r#type: String,
     hub_sn: String,
-    obs: Vec<Vec<f32>>,
+    obs: Vec<Vec<f64>>,
     firmware_revision: u16,
 }
 
@@ -953,284 +2855,406 @@ impl ObservationEvent {
         self.firmware_revision
     }
 
+    /// First observation row, which the getters of the event read
+    fn first_row(&self) -> ObservationRow<'_> {
+        ObservationRow {
+            obs: self.obs.first().map_or(&[], Vec::as_slice),
+        }
+    }
+
+    /// Every observation row of the event, as the API can batch several observations into one packet
+    pub fn observations(&self) -> impl Iterator<Item = ObservationRow<'_>> {
+        self.obs.iter().map(|obs| ObservationRow { obs })
+    }
+
     pub fn get_timestamp(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve timestamp from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[0];
+        self.first_row().get_timestamp()
+    }
 
-        Ok(data)
+    /// Retrieve the timestamp as whole epoch seconds without the rounding of an `f32`
+    pub fn get_timestamp_u64(&self) -> Result<u64, EventError> {
+        self.first_row().get_timestamp_u64()
     }
 
-    pub fn get_wind_lull(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve wind lull from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[1];
+    /// Timestamp as a `SystemTime`
+    pub fn timestamp_systemtime(&self) -> Result<SystemTime, EventError> {
+        self.get_timestamp_u64().map(epoch_seconds_to_systemtime)
+    }
 
-        Ok(data)
+    /// Timestamp as a UTC date and time, see `timestamp_systemtime`
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_datetime(&self) -> Result<DateTime<Utc>, EventError> {
+        self.timestamp_systemtime().map(DateTime::from)
     }
 
-    pub fn get_wind_avg(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve wind average from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[2];
+    pub fn get_wind_lull(&self) -> Result<f32, EventError> {
+        self.first_row().get_wind_lull()
+    }
 
-        Ok(data)
+    pub fn get_wind_avg(&self) -> Result<f32, EventError> {
+        self.first_row().get_wind_avg()
     }
 
     pub fn get_wind_gust(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve wind gust from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[3];
-
-        Ok(data)
+        self.first_row().get_wind_gust()
     }
 
     pub fn get_wind_direction(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve wind direction from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[4];
-
-        Ok(data)
+        self.first_row().get_wind_direction()
     }
 
     pub fn get_wind_sample_interval(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve wind sample interval from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[5];
-
-        Ok(data)
+        self.first_row().get_wind_sample_interval()
     }
 
     pub fn get_station_pressure(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve station pressure from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[6];
-
-        Ok(data)
+        self.first_row().get_station_pressure()
     }
 
     pub fn get_air_temperature(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve air temperature from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[7];
-
-        Ok(data)
+        self.first_row().get_air_temperature()
     }
 
     pub fn get_rh(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve R/H from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[8];
-
-        Ok(data)
+        self.first_row().get_rh()
     }
 
     pub fn get_illuminance(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve illuminance from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[9];
-
-        Ok(data)
+        self.first_row().get_illuminance()
     }
 
     pub fn get_uv(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve UV from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[10];
-
-        Ok(data)
+        self.first_row().get_uv()
     }
 
     pub fn get_solar_radiation(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve solar radiation from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[11];
-
-        Ok(data)
+        self.first_row().get_solar_radiation()
     }
 
     pub fn get_rain_amount_prev_min(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve previous minute's rain amount from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[12];
-
-        Ok(data)
+        self.first_row().get_rain_amount_prev_min()
     }
 
     pub fn get_precip_type(&self) -> Result<PrecipitationType, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve precipitation type from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[13];
-
-        match data as u16 {
-            0 => Ok(PrecipitationType::None),
-            1 => Ok(PrecipitationType::Rain),
-            2 => Ok(PrecipitationType::Hail),
-            3 => Ok(PrecipitationType::RainHail),
-            _ => {
-                eprintln!("Unknown precipitation type");
-                Err(EventError::UnexpectedValue)
-            }
-        }
+        self.first_row().get_precip_type()
     }
 
     pub fn get_lightning_avg_distance(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve average distance of lighting strike from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[14];
-
-        Ok(data)
+        self.first_row().get_lightning_avg_distance()
     }
 
     pub fn get_lightning_strike_count(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve lightning strike count from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[15];
-
-        Ok(data)
+        self.first_row().get_lightning_strike_count()
     }
 
     pub fn get_battery_voltage(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve battery voltage from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[16];
-
-        Ok(data)
+        self.first_row().get_battery_voltage()
     }
 
     pub fn get_report_interval(&self) -> Result<f32, EventError> {
-        let data = self.obs.first().ok_or_else(|| {
-            eprintln!(
-                "Unable to retrieve report interval from {}",
-                std::any::type_name::<Self>()
-            );
-            EventError::ParseError
-        })?[17];
+        self.first_row().get_report_interval()
+    }
 
-        Ok(data)
+    /// Wind direction as a point of the 16-point compass rose, e.g. "NNE"
+    pub fn wind_direction_cardinal(&self) -> Result<&'static str, EventError> {
+        self.get_wind_direction().map(cardinal_direction)
     }
-}
 
-/// Device status event for a station
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct DeviceStatusEvent {
-    serial_number: String,
-    r#type: String,
-    hub_sn: String,
-    timestamp: u64,
-    uptime: u64,
-    voltage: f32,
-    firmware_revision: u16,
-    rssi: i16,
-    hub_rssi: i16,
-    sensor_status: u32,
-    debug: u8,
-}
+    /// Air temperature (F, fahrenheit)
+    pub fn get_air_temperature_f(&self) -> Result<f32, EventError> {
+        self.get_air_temperature().map(celsius_to_fahrenheit)
+    }
 
-impl fmt::Display for DeviceStatusEvent {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "DeviceStatusEvent Data (Timestamp: {}, Serial Number: {}, Hub SN: {}, Firmware Revision: {}, Uptime: {}, Battery Voltage: {}V, RSSI: {}, Hub RSSI: {}, Debug Enabled: {})",
-            self.get_timestamp(),
-            self.get_serial_number(),
-            self.get_hub_sn(),
-            self.get_firmware_revision(),
-            self.get_uptime(),
-            self.get_battery_voltage(),
-            self.get_rssi(),
-            self.get_hub_rssi(),
-            self.debugging_enabled()
-        )
+    /// Average wind speed (mph, miles per hour)
+    pub fn get_wind_avg_mph(&self) -> Result<f32, EventError> {
+        self.get_wind_avg().map(mps_to_mph)
     }
-}
 
-impl DeviceStatusEvent {
-    pub fn get_serial_number(&self) -> String {
-        self.serial_number.clone()
+    /// Wind gust speed (mph, miles per hour)
+    pub fn get_wind_gust_mph(&self) -> Result<f32, EventError> {
+        self.get_wind_gust().map(mps_to_mph)
     }
 
-    pub fn get_hub_sn(&self) -> String {
-        self.hub_sn.clone()
+    /// Station pressure (inHg, inches of mercury)
+    pub fn get_station_pressure_inhg(&self) -> Result<f32, EventError> {
+        self.get_station_pressure().map(mb_to_inhg)
     }
 
-    pub fn get_timestamp(&self) -> u64 {
-        self.timestamp
+    /// Average lightning strike distance (mi, miles)
+    pub fn get_lightning_avg_distance_miles(&self) -> Result<f32, EventError> {
+        self.get_lightning_avg_distance().map(km_to_miles)
     }
 
-    pub fn get_uptime(&self) -> u64 {
-        self.uptime
+    /// Computes the dew point (C, celsius) from the air temperature and relative humidity with the Magnus-Tetens approximation
+    pub fn dew_point(&self) -> Result<f32, EventError> {
+        Ok(magnus_dew_point(
+            self.get_air_temperature()?,
+            self.get_rh()?,
+        ))
     }
 
-    pub fn get_battery_voltage(&self) -> f32 {
+    /// Computes the mixing ratio (g/kg) from the air temperature, relative humidity and station pressure
+    pub fn get_mixing_ratio(&self) -> Result<f32, EventError> {
+        Ok(mixing_ratio(
+            self.get_air_temperature()?,
+            self.get_rh()?,
+            self.get_station_pressure()?,
+        ))
+    }
+
+    /// Computes the specific humidity (g/kg) from the air temperature, relative humidity and station pressure
+    pub fn get_specific_humidity(&self) -> Result<f32, EventError> {
+        Ok(specific_humidity(
+            self.get_air_temperature()?,
+            self.get_rh()?,
+            self.get_station_pressure()?,
+        ))
+    }
+
+    /// Computes the potential temperature (C, celsius) from the air temperature and station pressure
+    pub fn get_potential_temperature(&self) -> Result<f32, EventError> {
+        Ok(potential_temperature(
+            self.get_air_temperature()?,
+            self.get_station_pressure()?,
+        ))
+    }
+
+    /// Estimate the visibility (km) from relative humidity and precipitation intensity
+    ///
+    /// **This is a rough heuristic, not a measurement.** Tempest stations have no visibility sensor, so
+    /// the estimate is derived from two proxies and is capped at `MAX_VISIBILITY_KM`:
+    ///
+    /// - Fog: visibility falls linearly from `MAX_VISIBILITY_KM` at `FOG_ONSET_RELATIVE_HUMIDITY` down to
+    ///   `SATURATED_VISIBILITY_KM` at saturation.
+    /// - Precipitation: the previous minute's rain is scaled to a rate R (mm/h) and visibility is taken as
+    ///   12 * R^-0.5 km, so light rain barely reduces visibility while heavy rain (50 mm/h) reduces it to under 2 km.
+    ///
+    /// The lower of the two estimates is returned. Haze, smoke, snow, and dust are not accounted for.
+    ///
+    /// Returns None if relative humidity or rain amount are not available
+    pub fn estimate_visibility_km(&self) -> Option<f32> {
+        let relative_humidity = self.get_rh().ok()?;
+        let rain_rate = self.get_rain_amount_prev_min().ok()? * 60.0;
+
+        let fog_visibility = if relative_humidity >= FOG_ONSET_RELATIVE_HUMIDITY {
+            let saturation = ((100.0 - relative_humidity) / (100.0 - FOG_ONSET_RELATIVE_HUMIDITY))
+                .clamp(0.0, 1.0);
+
+            SATURATED_VISIBILITY_KM + (MAX_VISIBILITY_KM - SATURATED_VISIBILITY_KM) * saturation
+        } else {
+            MAX_VISIBILITY_KM
+        };
+
+        let rain_visibility = if rain_rate > 0.0 {
+            12.0 * rain_rate.powf(-0.5)
+        } else {
+            MAX_VISIBILITY_KM
+        };
+
+        Some(fog_visibility.min(rain_visibility).min(MAX_VISIBILITY_KM))
+    }
+
+    /// Classify the wind as calm, steady, or gusty from the average and gust wind speeds
+    pub fn wind_regime(&self) -> Result<WindRegime, EventError> {
+        Ok(WindRegime::classify(
+            self.get_wind_avg()?,
+            self.get_wind_gust()?,
+        ))
+    }
+
+    /// Check the readings are finite and within their physical ranges
+    pub fn validate(&self) -> Result<(), EventError> {
+        check_range(self.get_wind_lull()?, 0.0, 100.0)?;
+        check_range(self.get_wind_avg()?, 0.0, 100.0)?;
+        check_range(self.get_wind_gust()?, 0.0, 100.0)?;
+        check_range(self.get_wind_direction()?, 0.0, 360.0)?;
+        check_range(self.get_station_pressure()?, 250.0, 1100.0)?;
+        check_range(self.get_air_temperature()?, -100.0, 100.0)?;
+        check_range(self.get_rh()?, 0.0, 100.0)?;
+        check_range(self.get_illuminance()?, 0.0, 200_000.0)?;
+        check_range(self.get_uv()?, 0.0, 25.0)?;
+        check_range(self.get_solar_radiation()?, 0.0, 2000.0)?;
+        check_range(self.get_rain_amount_prev_min()?, 0.0, f32::MAX)?;
+        check_range(self.get_lightning_avg_distance()?, 0.0, 100.0)?;
+        check_range(self.get_lightning_strike_count()?, 0.0, f32::MAX)?;
+        check_range(self.get_battery_voltage()?, 0.0, 5.0)
+    }
+}
+
+/// Single row of a station observation event's `obs` array
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObservationRow<'a> {
+    obs: &'a [f64],
+}
+
+impl ObservationRow<'_> {
+    /// Retrieve an entry of the row, which may be missing from a malformed event
+    fn get(&self, index: usize, name: &str) -> Result<f32, EventError> {
+        self.get_exact(index, name).map(|value| value as f32)
+    }
+
+    /// Retrieve an entry of the row without narrowing it to an `f32`
+    fn get_exact(&self, index: usize, name: &str) -> Result<f64, EventError> {
+        self.obs.get(index).copied().ok_or_else(|| {
+            debug!(
+                "Unable to retrieve {name} from {}",
+                std::any::type_name::<ObservationEvent>()
+            );
+            EventError::ParseError
+        })
+    }
+
+    pub fn get_timestamp(&self) -> Result<f32, EventError> {
+        self.get(0, "timestamp")
+    }
+
+    /// Retrieve the timestamp as whole epoch seconds without the rounding of an `f32`
+    pub fn get_timestamp_u64(&self) -> Result<u64, EventError> {
+        self.get_exact(0, "timestamp")
+            .map(|timestamp| timestamp as u64)
+    }
+
+    pub fn get_wind_lull(&self) -> Result<f32, EventError> {
+        self.get(1, "wind lull")
+    }
+
+    pub fn get_wind_avg(&self) -> Result<f32, EventError> {
+        self.get(2, "wind average")
+    }
+
+    pub fn get_wind_gust(&self) -> Result<f32, EventError> {
+        self.get(3, "wind gust")
+    }
+
+    pub fn get_wind_direction(&self) -> Result<f32, EventError> {
+        self.get(4, "wind direction")
+    }
+
+    pub fn get_wind_sample_interval(&self) -> Result<f32, EventError> {
+        self.get(5, "wind sample interval")
+    }
+
+    pub fn get_station_pressure(&self) -> Result<f32, EventError> {
+        self.get(6, "station pressure")
+    }
+
+    pub fn get_air_temperature(&self) -> Result<f32, EventError> {
+        self.get(7, "air temperature")
+    }
+
+    pub fn get_rh(&self) -> Result<f32, EventError> {
+        self.get(8, "R/H")
+    }
+
+    pub fn get_illuminance(&self) -> Result<f32, EventError> {
+        self.get(9, "illuminance")
+    }
+
+    pub fn get_uv(&self) -> Result<f32, EventError> {
+        self.get(10, "UV")
+    }
+
+    pub fn get_solar_radiation(&self) -> Result<f32, EventError> {
+        self.get(11, "solar radiation")
+    }
+
+    pub fn get_rain_amount_prev_min(&self) -> Result<f32, EventError> {
+        self.get(12, "previous minute's rain amount")
+    }
+
+    pub fn get_precip_type(&self) -> Result<PrecipitationType, EventError> {
+        match self.get(13, "precipitation type")? as u16 {
+            0 => Ok(PrecipitationType::None),
+            1 => Ok(PrecipitationType::Rain),
+            2 => Ok(PrecipitationType::Hail),
+            3 => Ok(PrecipitationType::RainHail),
+            _ => {
+                debug!("Unknown precipitation type");
+                Err(EventError::UnexpectedValue)
+            }
+        }
+    }
+
+    pub fn get_lightning_avg_distance(&self) -> Result<f32, EventError> {
+        self.get(14, "average distance of lighting strike")
+    }
+
+    pub fn get_lightning_strike_count(&self) -> Result<f32, EventError> {
+        self.get(15, "lightning strike count")
+    }
+
+    pub fn get_battery_voltage(&self) -> Result<f32, EventError> {
+        self.get(16, "battery voltage")
+    }
+
+    pub fn get_report_interval(&self) -> Result<f32, EventError> {
+        self.get(17, "report interval")
+    }
+}
+
+/// Device status event for a station
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeviceStatusEvent {
+    serial_number: String,
+    r#type: String,
+    hub_sn: String,
+    timestamp: u64,
+    uptime: u64,
+    voltage: f32,
+    firmware_revision: u16,
+    rssi: i16,
+    hub_rssi: i16,
+    sensor_status: u32,
+    debug: u8,
+}
+
+impl fmt::Display for DeviceStatusEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DeviceStatusEvent Data (Timestamp: {}, Serial Number: {}, Hub SN: {}, Firmware Revision: {}, Uptime: {}, Battery Voltage: {}V, RSSI: {}, Hub RSSI: {}, Debug Enabled: {})",
+            self.get_timestamp(),
+            self.get_serial_number(),
+            self.get_hub_sn(),
+            self.get_firmware_revision(),
+            self.get_uptime(),
+            self.get_battery_voltage(),
+            self.get_rssi(),
+            self.get_hub_rssi(),
+            self.debugging_enabled()
+        )
+    }
+}
+
+impl DeviceStatusEvent {
+    pub fn get_serial_number(&self) -> String {
+        self.serial_number.clone()
+    }
+
+    pub fn get_hub_sn(&self) -> String {
+        self.hub_sn.clone()
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Timestamp as a `SystemTime`
+    pub fn timestamp_systemtime(&self) -> SystemTime {
+        epoch_seconds_to_systemtime(self.timestamp)
+    }
+
+    /// Timestamp as a UTC date and time, see `timestamp_systemtime`
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_datetime(&self) -> DateTime<Utc> {
+        DateTime::from(self.timestamp_systemtime())
+    }
+
+    pub fn get_uptime(&self) -> u64 {
+        self.uptime
+    }
+
+    pub fn get_battery_voltage(&self) -> f32 {
         self.voltage
     }
 
@@ -1249,6 +3273,158 @@ impl DeviceStatusEvent {
     pub fn debugging_enabled(&self) -> bool {
         self.debug != 0
     }
+
+    /// Returns a builder for a device status event from `serial_number`, with the remaining fields defaulting
+    /// to those of the example in the UDP reference
+    pub fn builder(serial_number: &str) -> DeviceStatusEventBuilder {
+        DeviceStatusEventBuilder {
+            event: DeviceStatusEvent {
+                serial_number: serial_number.to_string(),
+                r#type: "device_status".to_string(),
+                hub_sn: "HB-00000001".to_string(),
+                timestamp: 1510855923,
+                uptime: 2189,
+                voltage: 3.50,
+                firmware_revision: 17,
+                rssi: -17,
+                hub_rssi: -87,
+                sensor_status: 0,
+                debug: 0,
+            },
+        }
+    }
+
+    /// Decode the sensor status bitfield reported by the device
+    pub fn get_sensor_status(&self) -> SensorStatus {
+        SensorStatus::from_bits(self.sensor_status)
+    }
+
+    /// Check the battery voltage is finite and within its physical range
+    pub fn validate(&self) -> Result<(), EventError> {
+        check_range(self.get_battery_voltage(), 0.0, 5.0)
+    }
+}
+
+/// Sensor faults and power booster state decoded from the `sensor_status` bitfield of a device status event
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SensorStatus {
+    pub lightning_failed: bool,
+    pub lightning_noise: bool,
+    pub lightning_disturber: bool,
+    pub pressure_failed: bool,
+    pub temperature_failed: bool,
+    pub rh_failed: bool,
+    pub wind_failed: bool,
+    pub precip_failed: bool,
+    pub light_uv_failed: bool,
+    pub power_booster_depleted: bool,
+    pub power_booster_shore_power: bool,
+}
+
+impl SensorStatus {
+    /// Decode the bitfield using the bits documented by the WeatherFlow UDP API, ignoring undocumented bits
+    pub fn from_bits(bits: u32) -> Self {
+        let set = |mask: u32| bits & mask != 0;
+
+        Self {
+            lightning_failed: set(0b0_0000_0001),
+            lightning_noise: set(0b0_0000_0010),
+            lightning_disturber: set(0b0_0000_0100),
+            pressure_failed: set(0b0_0000_1000),
+            temperature_failed: set(0b0_0001_0000),
+            rh_failed: set(0b0_0010_0000),
+            wind_failed: set(0b0_0100_0000),
+            precip_failed: set(0b0_1000_0000),
+            light_uv_failed: set(0b1_0000_0000),
+            power_booster_depleted: set(0x0000_8000),
+            power_booster_shore_power: set(0x0001_0000),
+        }
+    }
+
+    /// Labels of every flag that is set
+    fn active(&self) -> Vec<&'static str> {
+        [
+            (self.lightning_failed, "lightning failed"),
+            (self.lightning_noise, "lightning noise"),
+            (self.lightning_disturber, "lightning disturber"),
+            (self.pressure_failed, "pressure failed"),
+            (self.temperature_failed, "temperature failed"),
+            (self.rh_failed, "rh failed"),
+            (self.wind_failed, "wind failed"),
+            (self.precip_failed, "precip failed"),
+            (self.light_uv_failed, "light/uv failed"),
+            (self.power_booster_depleted, "power booster depleted"),
+            (self.power_booster_shore_power, "power booster shore power"),
+        ]
+        .into_iter()
+        .filter_map(|(set, label)| set.then_some(label))
+        .collect()
+    }
+}
+
+impl fmt::Display for SensorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let active = self.active();
+
+        if active.is_empty() {
+            write!(f, "Sensors OK")
+        } else {
+            write!(f, "{}", active.join(", "))
+        }
+    }
+}
+
+/// Builder for a `DeviceStatusEvent`, mainly for constructing events in tests
+#[derive(Debug, Clone)]
+pub struct DeviceStatusEventBuilder {
+    event: DeviceStatusEvent,
+}
+
+impl DeviceStatusEventBuilder {
+    pub fn hub_sn(mut self, hub_sn: &str) -> Self {
+        self.event.hub_sn = hub_sn.to_string();
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.event.timestamp = timestamp;
+        self
+    }
+
+    pub fn uptime(mut self, uptime: u64) -> Self {
+        self.event.uptime = uptime;
+        self
+    }
+
+    pub fn voltage(mut self, voltage: f32) -> Self {
+        self.event.voltage = voltage;
+        self
+    }
+
+    pub fn firmware_revision(mut self, firmware_revision: u16) -> Self {
+        self.event.firmware_revision = firmware_revision;
+        self
+    }
+
+    pub fn rssi(mut self, rssi: i16) -> Self {
+        self.event.rssi = rssi;
+        self
+    }
+
+    pub fn hub_rssi(mut self, hub_rssi: i16) -> Self {
+        self.event.hub_rssi = hub_rssi;
+        self
+    }
+
+    /// Raw sensor status bitfield, see `SensorStatus` for the meaning of each bit
+    pub fn sensor_status(mut self, sensor_status: u32) -> Self {
+        self.event.sensor_status = sensor_status;
+        self
+    }
+
+    pub fn build(self) -> DeviceStatusEvent {
+        self.event
+    }
 }
 
 /// Hub status event
@@ -1278,8 +3454,8 @@ impl fmt::Display for HubStatusEvent {
             self.get_uptime(),
             self.get_rssi(),
             self.get_reset_flags(),
-            self.get_radio_status(),
-            self.get_radio_network_id()
+            self.get_radio_status().unwrap_or(RadioStatus::Unknown),
+            self.get_radio_network_id().unwrap_or(0)
         )
     }
 }
@@ -1289,6 +3465,17 @@ impl HubStatusEvent {
         self.timestamp
     }
 
+    /// Timestamp as a `SystemTime`
+    pub fn timestamp_systemtime(&self) -> SystemTime {
+        epoch_seconds_to_systemtime(self.timestamp)
+    }
+
+    /// Timestamp as a UTC date and time, see `timestamp_systemtime`
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_datetime(&self) -> DateTime<Utc> {
+        DateTime::from(self.timestamp_systemtime())
+    }
+
     pub fn get_serial_number(&self) -> String {
         self.serial_number.clone()
     }
@@ -1305,39 +3492,132 @@ impl HubStatusEvent {
         self.rssi
     }
 
+    /// Returns a builder for a hub status event from `serial_number`, with the remaining fields defaulting
+    /// to those of the example in the UDP reference
+    pub fn builder(serial_number: &str) -> HubStatusEventBuilder {
+        HubStatusEventBuilder {
+            event: HubStatusEvent {
+                serial_number: serial_number.to_string(),
+                r#type: "hub_status".to_string(),
+                firmware_revision: "35".to_string(),
+                uptime: 1670133,
+                rssi: -62,
+                timestamp: 1495724691,
+                reset_flags: "BOR,PIN,POR".to_string(),
+                seq: 48,
+                fs: Some(vec![1, 0, 15675411, 524288]),
+                radio_stats: vec![2, 1, 0, 3, 2839],
+                mqtt_stats: vec![1, 0],
+            },
+        }
+    }
+
     pub fn get_reset_flags(&self) -> String {
         self.reset_flags.clone()
     }
 
-    pub fn get_radio_version(&self) -> u16 {
-        self.radio_stats[0]
+    /// Returns the reset flags as `ResetFlag`s, skipping unknown codes
+    pub fn get_reset_causes(&self) -> Vec<ResetFlag> {
+        reset_flags_from_codes(self.reset_flags.split(','))
+    }
+
+    /// Retrieve an entry of the radio stats, which some firmware sends fewer of
+    fn get_radio_stat(&self, index: usize, name: &str) -> Result<u16, EventError> {
+        self.radio_stats.get(index).copied().ok_or_else(|| {
+            debug!(
+                "Unable to retrieve {name} from {}",
+                std::any::type_name::<Self>()
+            );
+            EventError::ParseError
+        })
     }
 
-    pub fn get_radio_reboot_count(&self) -> u16 {
-        self.radio_stats[1]
+    pub fn get_radio_version(&self) -> Result<u16, EventError> {
+        self.get_radio_stat(0, "radio version")
     }
 
-    pub fn get_radio_i2c_error_count(&self) -> u16 {
-        self.radio_stats[2]
+    pub fn get_radio_reboot_count(&self) -> Result<u16, EventError> {
+        self.get_radio_stat(1, "radio reboot count")
     }
 
-    pub fn get_radio_status(&self) -> RadioStatus {
-        match self.radio_stats[3] {
-            0 => RadioStatus::RadioOff,
-            1 => RadioStatus::RadioOn,
-            3 => RadioStatus::RadioActive,
-            7 => RadioStatus::BLEConnected,
-            _ => RadioStatus::Unknown,
-        }
+    pub fn get_radio_i2c_error_count(&self) -> Result<u16, EventError> {
+        self.get_radio_stat(2, "radio I2C error count")
     }
 
-    pub fn get_radio_network_id(&self) -> u16 {
-        self.radio_stats[4]
+    pub fn get_radio_status(&self) -> Result<RadioStatus, EventError> {
+        self.get_radio_stat(3, "radio status")
+            .map(radio_status_from_u16)
+    }
+
+    pub fn get_radio_network_id(&self) -> Result<u16, EventError> {
+        self.get_radio_stat(4, "radio network ID")
+    }
+
+    /// Retrieve the full set of radio stats
+    pub fn get_radio_stats(&self) -> Result<RadioStats, EventError> {
+        RadioStats::from_raw(&self.radio_stats)
+    }
+
+    /// Check the event's values are within their expected ranges
+    ///
+    /// A hub status has no readings with a physical range, and radio stats missing from older or malformed events
+    /// are read as unavailable by their getters, so every hub status is accepted.
+    pub fn validate(&self) -> Result<(), EventError> {
+        Ok(())
     }
 }
 
-/// Radio stats from a hub status event
+/// Builder for a `HubStatusEvent`, mainly for constructing events in tests
 #[derive(Debug, Clone)]
+pub struct HubStatusEventBuilder {
+    event: HubStatusEvent,
+}
+
+impl HubStatusEventBuilder {
+    pub fn firmware_revision(mut self, firmware_revision: &str) -> Self {
+        self.event.firmware_revision = firmware_revision.to_string();
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.event.timestamp = timestamp;
+        self
+    }
+
+    pub fn uptime(mut self, uptime: u64) -> Self {
+        self.event.uptime = uptime;
+        self
+    }
+
+    pub fn rssi(mut self, rssi: i16) -> Self {
+        self.event.rssi = rssi;
+        self
+    }
+
+    /// Comma separated reset flag codes, such as `"BOR,PIN,POR"`
+    pub fn reset_flags(mut self, reset_flags: &str) -> Self {
+        self.event.reset_flags = reset_flags.to_string();
+        self
+    }
+
+    pub fn seq(mut self, seq: u32) -> Self {
+        self.event.seq = seq;
+        self
+    }
+
+    /// Raw radio stats, in the order version, reboot count, I2C bus error count, radio status and network ID
+    pub fn radio_stats(mut self, radio_stats: &[u16]) -> Self {
+        self.event.radio_stats = radio_stats.to_vec();
+        self
+    }
+
+    pub fn build(self) -> HubStatusEvent {
+        self.event
+    }
+}
+
+/// Radio stats from a hub status event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RadioStats {
     pub version: u16,
     pub reboot_count: u16,
@@ -1346,6 +3626,47 @@ pub struct RadioStats {
     pub radio_network_id: u16,
 }
 
+impl RadioStats {
+    /// Create radio stats from the raw `radio_stats` array of a hub status event
+    ///
+    /// Returns an `EventError::ParseError` if fewer than five stats are present
+    pub fn from_raw(radio_stats: &[u16]) -> Result<Self, EventError> {
+        match *radio_stats {
+            [
+                version,
+                reboot_count,
+                i2c_bus_error_count,
+                radio_status,
+                radio_network_id,
+                ..,
+            ] => Ok(Self {
+                version,
+                reboot_count,
+                i2c_bus_error_count,
+                radio_status: radio_status_from_u16(radio_status),
+                radio_network_id,
+            }),
+            _ => {
+                debug!("Unable to retrieve radio stats from {radio_stats:?}");
+                Err(EventError::ParseError)
+            }
+        }
+    }
+}
+
+/// Map a raw radio status from a hub status event to a `RadioStatus`
+///
+/// The UDP reference defines 0 (off), 1 (on), 3 (active) and 7 (BLE connected), any other code is unknown
+fn radio_status_from_u16(radio_status: u16) -> RadioStatus {
+    match radio_status {
+        0 => RadioStatus::RadioOff,
+        1 => RadioStatus::RadioOn,
+        3 => RadioStatus::RadioActive,
+        7 => RadioStatus::BLEConnected,
+        _ => RadioStatus::Unknown,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1546,6 +3867,7 @@ mod test {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn get_data_from_rainstart_event() {
         let rain = RainStartEvent {
             serial_number: "SK-00008453".to_string(),
@@ -1557,9 +3879,11 @@ mod test {
         assert_eq!(rain.get_serial_number(), "SK-00008453");
         assert_eq!(rain.get_hub_sn(), "HB-00000001");
         assert_eq!(rain.get_timestamp(), 1493322445);
+        assert_eq!(rain.try_get_timestamp(), Ok(1493322445));
     }
 
     #[test]
+    #[allow(deprecated)]
     fn get_data_from_lightning_event() {
         let lightning = LightningStrikeEvent {
             serial_number: "AR-00004049".to_string(),
@@ -1573,9 +3897,13 @@ mod test {
         assert_eq!(lightning.get_timestamp(), 1493322445);
         assert_eq!(lightning.get_strike_distance(), 27);
         assert_eq!(lightning.get_strike_energy(), 3848);
+        assert_eq!(lightning.try_get_timestamp(), Ok(1493322445));
+        assert_eq!(lightning.try_get_strike_distance(), Ok(27));
+        assert_eq!(lightning.try_get_strike_energy(), Ok(3848));
     }
 
     #[test]
+    #[allow(deprecated)]
     fn get_data_from_rapidwind_event() {
         let rapidwind = RapidWindEvent {
             serial_number: "SK-00008453".to_string(),
@@ -1587,6 +3915,7 @@ mod test {
         assert_eq!(rapidwind.get_serial_number(), "SK-00008453");
         assert_eq!(rapidwind.get_hub_sn(), "HB-00000001");
         assert_eq!(rapidwind.get_timestamp(), 1493322445);
+        assert_eq!(rapidwind.get_timestamp_checked(), Ok(1493322445));
         assert_eq!(rapidwind.get_wind_speed_mps(), 2.3);
         assert_eq!(rapidwind.get_wind_direction(), 128);
     }
@@ -1659,17 +3988,75 @@ mod test {
     }
 
     #[test]
-    fn get_data_from_observationevent() {
-        let observation = ObservationEvent {
-            serial_number: "ST-00000512".to_string(),
-            hub_sn: "HB-00013030".to_string(),
-            firmware_revision: 129,
-            r#type: "obs_st".to_string(),
-            obs: vec![vec![
-                1588948614.0,
-                0.18,
-                0.22,
-                0.27,
+    fn sky_precip_type_absent() {
+        let json = b"{
+            \"serial_number\": \"SK-00008453\",
+            \"type\": \"obs_sky\",
+            \"hub_sn\": \"HB-00000001\",
+            \"obs\": [[1493321340,9000,10,0.0,2.6,4.6,7.4,187,3.12,1,130,null]],
+            \"firmware_revision\": 29
+        }";
+
+        let sky: ObservationSkyEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to ObservationSkyEvent");
+
+        assert_eq!(sky.get_local_day_rain_accum(), Ok(None));
+        assert_eq!(sky.get_precip_type(), Err(EventError::ParseError));
+
+        let json = b"{
+            \"serial_number\": \"SK-00008453\",
+            \"type\": \"obs_sky\",
+            \"hub_sn\": \"HB-00000001\",
+            \"obs\": [[1493321340,9000,10,0.0,2.6,4.6,7.4,187,3.12,1,130,null,null,3]],
+            \"firmware_revision\": 29
+        }";
+
+        let sky: ObservationSkyEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to ObservationSkyEvent");
+
+        // present but not reported
+        assert_eq!(sky.get_precip_type(), Ok(PrecipitationType::None));
+    }
+
+    #[test]
+    fn truncated_sky() {
+        let json = b"{
+            \"serial_number\": \"SK-00008453\",
+            \"type\": \"obs_sky\",
+            \"hub_sn\": \"HB-00000001\",
+            \"obs\": [[1493321340,9000,10,0.0,2.6]],
+            \"firmware_revision\": 29
+        }";
+
+        let sky: ObservationSkyEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to ObservationSkyEvent");
+
+        assert_eq!(sky.get_timestamp(), Ok(Some(1493321340.0)));
+        assert_eq!(sky.get_wind_lull(), Ok(Some(2.6)));
+        assert_eq!(sky.get_wind_avg(), Err(EventError::ParseError));
+        assert_eq!(sky.get_wind_sample_interval(), Err(EventError::ParseError));
+
+        let sky = ObservationSkyEvent {
+            obs: vec![vec![]],
+            ..sky
+        };
+
+        assert_eq!(sky.get_timestamp(), Err(EventError::ParseError));
+        assert_eq!(sky.get_wind_sample_interval(), Err(EventError::ParseError));
+    }
+
+    #[test]
+    fn get_data_from_observationevent() {
+        let observation = ObservationEvent {
+            serial_number: "ST-00000512".to_string(),
+            hub_sn: "HB-00013030".to_string(),
+            firmware_revision: 129,
+            r#type: "obs_st".to_string(),
+            obs: vec![vec![
+                1588948614.0,
+                0.18,
+                0.22,
+                0.27,
                 144.0,
                 6.0,
                 1017.57,
@@ -1759,9 +4146,1220 @@ mod test {
         assert_eq!(hub_status.get_rssi(), -62);
         assert_eq!(hub_status.get_timestamp(), 1495724691);
         assert_eq!(hub_status.get_reset_flags(), "BOR,PIN,POR");
-        assert_eq!(hub_status.get_radio_version(), 2);
-        assert_eq!(hub_status.get_radio_reboot_count(), 1);
-        assert_eq!(hub_status.get_radio_status(), RadioStatus::RadioActive);
-        assert_eq!(hub_status.get_radio_network_id(), 2839);
+        assert_eq!(
+            hub_status.get_reset_causes(),
+            vec![ResetFlag::BrownOut, ResetFlag::PinReset, ResetFlag::PowerOn]
+        );
+        assert_eq!(hub_status.get_radio_version(), Ok(2));
+        assert_eq!(hub_status.get_radio_reboot_count(), Ok(1));
+        assert_eq!(hub_status.get_radio_status(), Ok(RadioStatus::RadioActive));
+        assert_eq!(hub_status.get_radio_network_id(), Ok(2839));
+    }
+
+    fn observation_with_wind(wind_avg: f32, wind_gust: f32) -> ObservationEvent {
+        ObservationEvent {
+            serial_number: "ST-00000512".to_string(),
+            hub_sn: "HB-00013030".to_string(),
+            firmware_revision: 129,
+            r#type: "obs_st".to_string(),
+            obs: vec![vec![
+                1588948614.0,
+                0.0,
+                wind_avg.into(),
+                wind_gust.into(),
+                144.0,
+                6.0,
+                1017.57,
+                22.37,
+                50.26,
+                328.0,
+                0.03,
+                3.0,
+                0.000000,
+                0.0,
+                0.0,
+                0.0,
+                2.410,
+                1.0,
+            ]],
+        }
+    }
+
+    #[test]
+    fn wind_regime_calm() {
+        let observation = observation_with_wind(0.0, 0.0);
+
+        assert_eq!(observation.wind_regime(), Ok(WindRegime::Calm));
+    }
+
+    #[test]
+    fn wind_regime_steady() {
+        let observation = observation_with_wind(6.0, 7.0);
+
+        assert_eq!(observation.wind_regime(), Ok(WindRegime::Steady));
+    }
+
+    #[test]
+    fn wind_regime_gusty() {
+        let observation = observation_with_wind(4.0, 9.0);
+
+        assert_eq!(observation.wind_regime(), Ok(WindRegime::Gusty));
+    }
+
+    #[test]
+    fn observationair_timestamp_precision() {
+        let json = b"{
+            \"serial_number\": \"AR-00004049\",
+            \"type\": \"obs_air\",
+            \"hub_sn\": \"HB-00000001\",
+            \"obs\": [[1493164835,835.0,10.0,45,0,0,3.46,1]],
+            \"firmware_revision\": 17
+        }";
+
+        let air: ObservationAirEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to ObservationAirEvent");
+
+        // an f32 cannot represent the epoch exactly
+        assert_ne!(1493164835_f32 as u64, 1493164835);
+        assert_eq!(air.get_timestamp_u64(), Ok(1493164835));
+    }
+
+    fn observation_with_humidity_and_rain(
+        relative_humidity: f32,
+        rain_prev_min: f32,
+    ) -> ObservationEvent {
+        ObservationEvent {
+            serial_number: "ST-00000512".to_string(),
+            hub_sn: "HB-00013030".to_string(),
+            firmware_revision: 129,
+            r#type: "obs_st".to_string(),
+            obs: vec![vec![
+                1588948614.0,
+                0.18,
+                0.22,
+                0.27,
+                144.0,
+                6.0,
+                1017.57,
+                22.37,
+                relative_humidity.into(),
+                328.0,
+                0.03,
+                3.0,
+                rain_prev_min.into(),
+                0.0,
+                0.0,
+                0.0,
+                2.410,
+                1.0,
+            ]],
+        }
+    }
+
+    #[test]
+    fn visibility_foggy() {
+        let observation = observation_with_humidity_and_rain(99.0, 0.0);
+
+        let visibility = observation
+            .estimate_visibility_km()
+            .expect("Unable to estimate visibility");
+        assert!(visibility < 2.0);
+    }
+
+    #[test]
+    fn visibility_heavy_rain() {
+        // 50 mm/h
+        let observation = observation_with_humidity_and_rain(80.0, 50.0 / 60.0);
+
+        let visibility = observation
+            .estimate_visibility_km()
+            .expect("Unable to estimate visibility");
+        assert!(visibility < 2.0);
+    }
+
+    #[test]
+    fn visibility_clear() {
+        let observation = observation_with_humidity_and_rain(50.26, 0.0);
+
+        assert_eq!(
+            observation.estimate_visibility_km(),
+            Some(MAX_VISIBILITY_KM)
+        );
+    }
+
+    #[test]
+    fn observation_into_station_lightning() {
+        let json = b"{
+            \"serial_number\": \"ST-00000512\",
+            \"type\": \"obs_st\" ,
+            \"hub_sn\": \"HB-00013030\",
+            \"obs\": [
+                [1588948614,0.18,0.22,0.27,144,6,1017.57,22.37,50.26,328,0.03,3,0.000000,0,12,3,2.410,1]
+            ],
+            \"firmware_revision\": 129
+        }";
+
+        let observation: ObservationEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to ObservationEvent");
+
+        let station: Station = observation.into();
+
+        // index 14 is the average distance and index 15 the strike count
+        assert_eq!(station.lightning_strike_avg_distance, Some(12.0));
+        assert_eq!(station.lightning_strike_count, Some(3.0));
+    }
+
+    #[test]
+    fn observation_into_station_wind_and_solar() {
+        let json = b"{
+            \"serial_number\": \"ST-00000512\",
+            \"type\": \"obs_st\" ,
+            \"hub_sn\": \"HB-00013030\",
+            \"obs\": [
+                [1588948614,0.18,0.22,0.27,144,6,1017.57,22.37,50.26,328,0.03,3,0.000000,0,0,0,2.410,1]
+            ],
+            \"firmware_revision\": 129
+        }";
+
+        let observation: ObservationEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to ObservationEvent");
+
+        let station: Station = observation.into();
+
+        assert_eq!(station.wind_lull, Some(0.18));
+        assert_eq!(station.wind_avg, Some(0.22));
+        assert_eq!(station.wind_gust, Some(0.27));
+        assert_eq!(station.wind_direction, Some(144.0));
+        assert_eq!(station.uv, Some(0.03));
+        assert_eq!(station.solar_radiation, Some(3.0));
+    }
+
+    #[test]
+    fn potential_temperature_from_observation() {
+        let observation = observation_with_wind(0.22, 0.27);
+
+        // 295.52 K * (1000 / 1017.57)^0.286 = 294.05 K
+        let theta = observation
+            .get_potential_temperature()
+            .expect("Unable to compute potential temperature");
+        assert!((theta - 20.90).abs() < 0.01);
+    }
+
+    #[test]
+    fn validate_observation() {
+        assert_eq!(observation_with_wind(0.22, 0.27).validate(), Ok(()));
+
+        let mut observation = observation_with_wind(0.22, 0.27);
+        observation.obs[0][7] = f64::NAN;
+        assert_eq!(observation.validate(), Err(EventError::UnexpectedValue));
+
+        let mut observation = observation_with_wind(0.22, 0.27);
+        observation.obs[0][8] = 101.0;
+        assert_eq!(observation.validate(), Err(EventError::UnexpectedValue));
+    }
+
+    #[test]
+    fn truncated_observation() {
+        let json = b"{
+            \"serial_number\": \"ST-00000512\",
+            \"type\": \"obs_st\" ,
+            \"hub_sn\": \"HB-00013030\",
+            \"obs\": [
+                [1588948614,0.18,0.22,0.27,144,6,1017.57]
+            ],
+            \"firmware_revision\": 129
+        }";
+
+        let observation: ObservationEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to ObservationEvent");
+
+        assert_eq!(observation.get_station_pressure(), Ok(1017.57));
+        assert_eq!(
+            observation.get_air_temperature(),
+            Err(EventError::ParseError)
+        );
+        assert_eq!(observation.get_precip_type(), Err(EventError::ParseError));
+        assert_eq!(
+            observation.get_report_interval(),
+            Err(EventError::ParseError)
+        );
+        assert_eq!(observation.validate(), Err(EventError::ParseError));
+    }
+
+    #[test]
+    fn truncated_air_observation() {
+        let json = b"{
+            \"serial_number\": \"AR-00004049\",
+            \"type\": \"obs_air\",
+            \"hub_sn\": \"HB-00000001\",
+            \"obs\": [[1493164835,835.0,10.0]],
+            \"firmware_revision\": 17
+        }";
+
+        let observation: ObservationAirEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to ObservationAirEvent");
+
+        assert_eq!(observation.get_air_temperature(), Ok(10.0));
+        assert_eq!(
+            observation.get_relative_humidity(),
+            Err(EventError::ParseError)
+        );
+        assert_eq!(observation.dew_point(), Err(EventError::ParseError));
+        assert_eq!(
+            observation.get_battery_voltage(),
+            Err(EventError::ParseError)
+        );
+        assert_eq!(
+            observation.get_report_interval(),
+            Err(EventError::ParseError)
+        );
+    }
+
+    #[test]
+    fn empty_rain_event() {
+        let rain = RainStartEvent {
+            serial_number: "ST-00000512".to_string(),
+            r#type: "evt_precip".to_string(),
+            hub_sn: "HB-00000001".to_string(),
+            evt: vec![],
+        };
+
+        assert_eq!(rain.try_get_timestamp(), Err(EventError::ParseError));
+
+        let station = Station::from(rain);
+        assert_eq!(station.prev_rain_timestamp, None);
+    }
+
+    #[test]
+    fn empty_lightning_event() {
+        let lightning = LightningStrikeEvent {
+            serial_number: "ST-00000512".to_string(),
+            r#type: "evt_strike".to_string(),
+            hub_sn: "HB-00000001".to_string(),
+            evt: vec![],
+        };
+
+        assert_eq!(lightning.try_get_timestamp(), Err(EventError::ParseError));
+        assert_eq!(
+            lightning.try_get_strike_distance(),
+            Err(EventError::ParseError)
+        );
+        assert_eq!(
+            lightning.try_get_strike_energy(),
+            Err(EventError::ParseError)
+        );
+    }
+
+    #[test]
+    fn frost_risk_clear_cold_night() {
+        let mut station = Station::from(observation_with_humidity_and_rain(95.0, 0.0));
+        station.air_temperature = Some(1.0);
+        station.solar_radiation = Some(0.0);
+
+        assert_eq!(station.is_freezing(), Some(false));
+        assert_eq!(station.frost_risk(), Some(FrostRisk::Likely));
+
+        // the same conditions in daylight only make frost possible
+        station.solar_radiation = Some(250.0);
+        assert_eq!(station.frost_risk(), Some(FrostRisk::Possible));
+    }
+
+    #[test]
+    fn frost_risk_mild() {
+        let mut station = Station::from(observation_with_humidity_and_rain(50.0, 0.0));
+        station.air_temperature = Some(15.0);
+        station.solar_radiation = Some(0.0);
+
+        assert_eq!(station.is_freezing(), Some(false));
+        assert_eq!(station.frost_risk(), Some(FrostRisk::None));
+    }
+
+    #[test]
+    fn imperial_units() {
+        let observation = observation_with_wind(4.0, 10.0);
+
+        let temperature = observation
+            .get_air_temperature_f()
+            .expect("Unable to retrieve air temperature");
+        assert!((temperature - 72.27).abs() < 0.01);
+
+        let station = Station::from(observation);
+
+        let temperature = station
+            .air_temperature_f()
+            .expect("Unable to retrieve air temperature");
+        assert!((temperature - 72.27).abs() < 0.01);
+
+        let wind_avg = station.wind_avg_mph().expect("Unable to retrieve wind");
+        assert!((wind_avg - 8.95).abs() < 0.01);
+
+        let wind_gust = station.wind_gust_mph().expect("Unable to retrieve gust");
+        assert!((wind_gust - 22.37).abs() < 0.01);
+
+        let pressure = station
+            .station_pressure_inhg()
+            .expect("Unable to retrieve station pressure");
+        assert!((pressure - 30.05).abs() < 0.01);
+
+        assert_eq!(station.lightning_distance_miles(), Some(0.0));
+    }
+
+    #[test]
+    fn hub_status_with_short_radio_stats() {
+        let json = b"{
+            \"serial_number\": \"HB-00013030\",
+            \"type\": \"hub_status\",
+            \"firmware_revision\": \"35\",
+            \"uptime\": 1670133,
+            \"rssi\": -62,
+            \"timestamp\": 1495724691,
+            \"reset_flags\": \"BOR,PIN,POR\",
+            \"seq\": 48,
+            \"radio_stats\": [2, 1, 0],
+            \"mqtt_stats\": [1, 0]
+        }";
+
+        let hub_status: HubStatusEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to HubStatusEvent");
+
+        assert_eq!(hub_status.fs, None);
+        assert_eq!(hub_status.get_radio_version(), Ok(2));
+        assert_eq!(hub_status.get_radio_i2c_error_count(), Ok(0));
+        assert_eq!(hub_status.get_radio_status(), Err(EventError::ParseError));
+        assert_eq!(
+            hub_status.get_radio_network_id(),
+            Err(EventError::ParseError)
+        );
+        assert!(hub_status.get_radio_stats().is_err());
+        assert_eq!(hub_status.validate(), Ok(()));
+
+        // display and conversion into a hub fall back rather than panic
+        assert!(hub_status.to_string().contains("Radio Status: Unknown"));
+
+        let hub = Hub::from(hub_status);
+        assert_eq!(hub.radio_stats.radio_network_id, 0);
+    }
+
+    #[test]
+    fn cardinal_direction_boundaries() {
+        assert_eq!(cardinal_direction(0.0), "N");
+        assert_eq!(cardinal_direction(11.24), "N");
+        assert_eq!(cardinal_direction(11.25), "NNE");
+        assert_eq!(cardinal_direction(348.74), "NNW");
+        assert_eq!(cardinal_direction(348.75), "N");
+        assert_eq!(cardinal_direction(360.0), "N");
+        assert_eq!(cardinal_direction(225.0), "SW");
+    }
+
+    #[test]
+    fn wind_direction_cardinal() {
+        let observation = observation_with_wind(0.22, 0.27);
+        assert_eq!(observation.wind_direction_cardinal(), Ok("SE"));
+
+        let station = Station::from(observation);
+        assert_eq!(station.wind_direction_cardinal(), Some("SE"));
+    }
+
+    #[test]
+    fn mixing_ratio_and_specific_humidity() {
+        // 20 C at 50% relative humidity and 1013 MB has a vapour pressure of 11.66 MB
+        assert!((mixing_ratio(20.0, 50.0, 1013.0) - 7.245).abs() < 0.01);
+        assert!((specific_humidity(20.0, 50.0, 1013.0) - 7.193).abs() < 0.01);
+
+        let mut observation = observation_with_humidity_and_rain(50.0, 0.0);
+        observation.obs[0][6] = 1013.0;
+        observation.obs[0][7] = 20.0;
+
+        let mixing_ratio = observation
+            .get_mixing_ratio()
+            .expect("Unable to compute mixing ratio");
+        let specific_humidity = observation
+            .get_specific_humidity()
+            .expect("Unable to compute specific humidity");
+
+        assert!((mixing_ratio - 7.245).abs() < 0.01);
+        assert!((specific_humidity - 7.193).abs() < 0.01);
+    }
+
+    #[test]
+    fn dew_point() {
+        let observation = observation_with_wind(0.22, 0.27);
+
+        // 22.37 C at 50.26% relative humidity
+        let dew_point = observation
+            .dew_point()
+            .expect("Unable to compute dew point");
+        assert!((dew_point - 11.5).abs() < 0.1);
+
+        let air: ObservationAirEvent = serde_json::from_slice(
+            b"{
+            \"serial_number\": \"AR-00004049\",
+            \"type\": \"obs_air\",
+            \"hub_sn\": \"HB-00000001\",
+            \"obs\": [[1493164835,835.0,22.37,50.26,0,0,3.46,1]],
+            \"firmware_revision\": 17
+        }",
+        )
+        .expect("Unable to convert JSON to ObservationAirEvent");
+
+        let dew_point = air.dew_point().expect("Unable to compute dew point");
+        assert!((dew_point - 11.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn feels_like_hot() {
+        let mut station = Station::from(observation_with_humidity_and_rain(70.0, 0.0));
+        station.air_temperature = Some(32.0);
+
+        let feels_like = station.feels_like().expect("Unable to compute feels like");
+        assert!((feels_like - 40.41).abs() < 0.05);
+
+        station.relative_humidity = None;
+        assert_eq!(station.feels_like(), None);
+    }
+
+    #[test]
+    fn feels_like_cold() {
+        let mut station = Station::from(observation_with_wind(5.0, 7.0));
+        station.air_temperature = Some(-5.0);
+
+        let feels_like = station.feels_like().expect("Unable to compute feels like");
+        assert!((feels_like + 11.19).abs() < 0.05);
+
+        // too little wind for wind chill
+        station.wind_avg = Some(1.0);
+        assert_eq!(station.feels_like(), Some(-5.0));
+
+        station.wind_avg = None;
+        assert_eq!(station.feels_like(), None);
+    }
+
+    #[test]
+    fn feels_like_neutral() {
+        let mut station = Station::from(observation_with_wind(5.0, 7.0));
+        assert_eq!(station.feels_like(), Some(22.37));
+
+        station.air_temperature = None;
+        assert_eq!(station.feels_like(), None);
+    }
+
+    #[test]
+    fn calm_rapidwind_without_direction() {
+        let json = b"{
+            \"serial_number\": \"ST-00000512\",
+            \"type\": \"rapid_wind\",
+            \"hub_sn\": \"HB-00000001\",
+            \"ob\": [1493322445,0]
+        }";
+
+        let rapidwind: RapidWindEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to RapidWindEvent");
+
+        assert_eq!(rapidwind.get_timestamp_checked(), Ok(1493322445));
+        assert_eq!(rapidwind.get_wind_speed_mps(), 0.0);
+        assert_eq!(rapidwind.get_wind_direction(), 0);
+        assert_eq!(rapidwind.validate(), Ok(()));
+
+        let json = b"{
+            \"serial_number\": \"ST-00000512\",
+            \"type\": \"rapid_wind\",
+            \"hub_sn\": \"HB-00000001\",
+            \"ob\": [1493322445]
+        }";
+
+        let rapidwind: RapidWindEvent =
+            serde_json::from_slice(json).expect("Unable to convert JSON to RapidWindEvent");
+
+        assert_eq!(rapidwind.get_wind_speed_mps(), 0.0);
+        assert_eq!(rapidwind.get_wind_direction(), 0);
+    }
+
+    #[test]
+    fn metric_summary() {
+        let summary =
+            MetricSummary::from_values([20.0, 24.0, 22.0]).expect("Unable to summarise readings");
+
+        assert_eq!(summary.average, 22.0);
+        assert_eq!(summary.min, 20.0);
+        assert_eq!(summary.max, 24.0);
+        assert_eq!(summary.count, 3);
+
+        assert_eq!(MetricSummary::from_values([]), None);
+    }
+
+    #[test]
+    fn station_json_round_trip() {
+        let rapidwind = RapidWindEvent {
+            serial_number: "SK-00008453".to_string(),
+            r#type: "rapid_wind".to_string(),
+            hub_sn: "HB-00000001".to_string(),
+            ob: vec![1493322445.0, 2.3, 128.0],
+        };
+
+        let mut station: Station = rapidwind.clone().into();
+        station.precipitation_type = Some(PrecipitationType::RainHail);
+        station.air_temperature_samples.push_back(22.37);
+        station.temperature_minutes.insert(2237, 1.5);
+        station.last_seen = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1493322445));
+
+        let json = serde_json::to_value(&station).expect("Unable to serialize station");
+
+        assert_eq!(json["precipitation_type"], "RainHail");
+
+        let parsed: Station =
+            serde_json::from_value(json.clone()).expect("Unable to deserialize station");
+
+        assert_eq!(parsed.serial_number, "SK-00008453");
+        assert_eq!(parsed.precipitation_type, Some(PrecipitationType::RainHail));
+        assert_eq!(
+            parsed.air_temperature_samples,
+            station.air_temperature_samples
+        );
+        assert_eq!(parsed.temperature_minutes, station.temperature_minutes);
+        assert_eq!(parsed.last_seen, station.last_seen);
+        assert_eq!(parsed.wind_event, Some(rapidwind));
+        assert_eq!(
+            serde_json::to_value(&parsed).expect("Unable to serialize station"),
+            json
+        );
+    }
+
+    #[test]
+    fn radio_status_serializes_to_tag() {
+        let radio_stats =
+            RadioStats::from_raw(&[2, 1, 0, 3, 2839]).expect("Unable to parse radio stats");
+
+        let json = serde_json::to_value(&radio_stats).expect("Unable to serialize radio stats");
+
+        assert_eq!(json["radio_status"], "RadioActive");
+        assert_eq!(json["radio_network_id"], 2839);
+    }
+
+    #[test]
+    fn event_to_record() {
+        use crate::test_common::{
+            get_rapidwind_payload, get_sky_payload, get_station_observation_payload,
+        };
+
+        let rapidwind: RapidWindEvent = serde_json::from_slice(&get_rapidwind_payload())
+            .expect("Unable to convert JSON to RapidWindEvent");
+        let observation: ObservationEvent =
+            serde_json::from_slice(&get_station_observation_payload())
+                .expect("Unable to convert JSON to ObservationEvent");
+
+        let wind_record = EventType::RapidWind(rapidwind).to_record();
+        let observation_record = EventType::Observation(observation).to_record();
+
+        let mut columns = RECORD_COLUMNS.to_vec();
+        columns.sort();
+
+        for record in [&wind_record, &observation_record] {
+            assert_eq!(
+                record.keys().map(String::as_str).collect::<Vec<_>>(),
+                columns
+            );
+        }
+
+        assert_eq!(wind_record["event_type"], "rapid_wind");
+        assert_eq!(wind_record["timestamp"], 1493322445);
+        assert_eq!(wind_record["wind_direction"], 128);
+        assert_eq!(wind_record["air_temperature"], Value::Null);
+        assert_eq!(wind_record["precipitation_type"], Value::Null);
+
+        assert_eq!(observation_record["event_type"], "obs_st");
+        assert_eq!(observation_record["serial_number"], "ST-00000512");
+        assert_eq!(observation_record["timestamp"], 1588948614);
+
+        let sky: ObservationSkyEvent = serde_json::from_slice(&get_sky_payload())
+            .expect("Unable to convert JSON to ObservationSkyEvent");
+        assert_eq!(EventType::Sky(sky).to_record()["timestamp"], 1493321340);
+        assert_eq!(observation_record["air_temperature"], 22.37f32 as f64);
+        assert_eq!(observation_record["precipitation_type"], "None");
+        assert_eq!(observation_record["wind_speed"], Value::Null);
+        assert_eq!(observation_record["strike_energy"], Value::Null);
+    }
+
+    #[test]
+    fn apparent_wind() {
+        // sailing into a northerly
+        let (speed, direction) = true_to_apparent_wind(5.0, 0.0, 3.0, 0.0);
+        assert!((speed - 8.0).abs() < 0.001);
+        assert!(direction.abs() < 0.001);
+
+        // running before it
+        let (speed, direction) = true_to_apparent_wind(5.0, 0.0, 3.0, 180.0);
+        assert!((speed - 2.0).abs() < 0.001);
+        assert_eq!(cardinal_direction(direction), "N");
+
+        // a beam wind swings towards the bow
+        let (speed, direction) = true_to_apparent_wind(4.0, 90.0, 4.0, 0.0);
+        assert!((speed - 32f32.sqrt()).abs() < 0.001);
+        assert!((direction - 45.0).abs() < 0.001);
+
+        let (speed, direction) = apparent_to_true_wind(speed, direction, 4.0, 0.0);
+        assert!((speed - 4.0).abs() < 0.001);
+        assert!((direction - 90.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn empty_rapidwind() {
+        let rapidwind = RapidWindEvent {
+            serial_number: "ST-00000512".to_string(),
+            r#type: "rapid_wind".to_string(),
+            hub_sn: "HB-00000001".to_string(),
+            ob: vec![],
+        };
+
+        assert_eq!(
+            rapidwind.get_timestamp_checked(),
+            Err(EventError::ParseError)
+        );
+        assert!(rapidwind.to_string().contains("Timestamp: 0"));
+
+        let station: Station = rapidwind.into();
+        assert_eq!(station.wind_direction, None);
+    }
+
+    #[test]
+    fn parse_test_payloads() {
+        use crate::test_common::*;
+
+        assert!(matches!(
+            parse_event(&get_rain_payload()),
+            Ok(EventType::Rain(_))
+        ));
+        assert!(matches!(
+            parse_event(&get_lightning_payload()),
+            Ok(EventType::Lightning(_))
+        ));
+        assert!(matches!(
+            parse_event(&get_rapidwind_payload()),
+            Ok(EventType::RapidWind(_))
+        ));
+        assert!(matches!(
+            parse_event(&get_station_observation_payload()),
+            Ok(EventType::Observation(_))
+        ));
+        assert!(matches!(
+            parse_event(&get_air_payload()),
+            Ok(EventType::Air(_))
+        ));
+        assert!(matches!(
+            parse_event(&get_sky_payload()),
+            Ok(EventType::Sky(_))
+        ));
+        assert!(matches!(
+            parse_event(&get_device_payload()),
+            Ok(EventType::DeviceStatus(_))
+        ));
+        assert!(matches!(
+            EventType::try_from(get_hub_payload().as_slice()),
+            Ok(EventType::HubStatus(_))
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_payloads() {
+        assert_eq!(parse_event(b"not json").err(), Some(EventError::ParseError));
+        assert_eq!(
+            parse_event(b"{\"type\": \"rapid_wind\"}").err(),
+            Some(EventError::ParseError)
+        );
+        assert_eq!(
+            parse_event(b"{\"type\": \"obs_future\"}").err(),
+            Some(EventError::UnknownEventType)
+        );
+        assert_eq!(parse_event(b"{}").err(), Some(EventError::UnknownEventType));
+    }
+
+    #[test]
+    fn deserialize_event_type() {
+        use crate::test_common::*;
+
+        let parse = |payload: Vec<u8>| {
+            serde_json::from_slice::<EventType>(&payload).expect("Unable to deserialize EventType")
+        };
+
+        assert!(matches!(parse(get_rain_payload()), EventType::Rain(_)));
+        assert!(matches!(
+            parse(get_lightning_payload()),
+            EventType::Lightning(_)
+        ));
+        assert!(matches!(
+            parse(get_rapidwind_payload()),
+            EventType::RapidWind(_)
+        ));
+        assert!(matches!(
+            parse(get_station_observation_payload()),
+            EventType::Observation(_)
+        ));
+        assert!(matches!(parse(get_air_payload()), EventType::Air(_)));
+        assert!(matches!(parse(get_sky_payload()), EventType::Sky(_)));
+        assert!(matches!(
+            parse(get_device_payload()),
+            EventType::DeviceStatus(_)
+        ));
+        assert!(matches!(parse(get_hub_payload()), EventType::HubStatus(_)));
+
+        // the type tag is kept by the event
+        let record = parse(get_rapidwind_payload()).to_record();
+        assert_eq!(record["event_type"], "rapid_wind");
+
+        assert!(matches!(
+            parse(b"{\"type\": \"obs_future\"}".to_vec()),
+            EventType::Unknown { event_type, .. } if event_type == "obs_future"
+        ));
+
+        let error = serde_json::from_slice::<EventType>(b"{\"serial_number\": \"ST-00000512\"}")
+            .expect_err("Event without a type tag deserialized");
+        assert!(error.to_string().contains("type"));
+    }
+
+    #[test]
+    fn unknown_event_round_trip() {
+        let json = serde_json::json!({
+            "serial_number": "ST-00000512",
+            "type": "evt_future",
+            "hub_sn": "HB-00000001",
+            "evt": [1493322445, 7]
+        });
+        let event = EventType::Unknown {
+            event_type: "evt_future".to_string(),
+            raw: json.clone(),
+        };
+
+        let serialized = serde_json::to_string(&event).expect("Unable to serialize EventType");
+        let deserialized: EventType =
+            serde_json::from_str(&serialized).expect("Unable to deserialize EventType");
+
+        match deserialized {
+            EventType::Unknown { event_type, raw } => {
+                assert_eq!(event_type, "evt_future");
+                assert_eq!(raw, json);
+            }
+            other => panic!("Unexpected event {other:?}"),
+        }
+    }
+
+    #[test]
+    fn sensor_status_bits() {
+        assert_eq!(SensorStatus::from_bits(0), SensorStatus::default());
+        assert_eq!(SensorStatus::from_bits(0).to_string(), "Sensors OK");
+
+        let status = SensorStatus::from_bits(0b1000);
+        assert!(status.pressure_failed);
+        assert_eq!(status.to_string(), "pressure failed");
+
+        let status = SensorStatus::from_bits(0b10_0000);
+        assert!(status.rh_failed);
+        assert!(!status.pressure_failed);
+
+        let status = SensorStatus::from_bits(0b1_0000_0011 | 0x0001_0000);
+        assert_eq!(
+            status.to_string(),
+            "lightning failed, lightning noise, light/uv failed, power booster shore power"
+        );
+
+        let device = DeviceStatusEvent {
+            serial_number: "AR-00004049".to_string(),
+            r#type: "device_status".to_string(),
+            hub_sn: "HB-00000001".to_string(),
+            timestamp: 1510855923,
+            uptime: 2189,
+            voltage: 3.50,
+            firmware_revision: 17,
+            rssi: -17,
+            hub_rssi: -87,
+            sensor_status: 0b100_0000,
+            debug: 0,
+        };
+
+        assert!(device.get_sensor_status().wind_failed);
+    }
+
+    #[test]
+    fn sky_par() {
+        let sky: ObservationSkyEvent =
+            serde_json::from_slice(&crate::test_common::get_sky_payload())
+                .expect("Unable to convert JSON to ObservationSkyEvent");
+
+        assert_eq!(sky.get_solar_radiation(), Ok(Some(130.0)));
+        assert_eq!(sky.get_par(), Ok(Some(262.6)));
+    }
+
+    #[test]
+    fn reset_flags() {
+        assert_eq!(
+            reset_flags_from_codes("SFT,WDG,WWD,LPW".split(',')),
+            vec![
+                ResetFlag::SoftwareReset,
+                ResetFlag::WatchdogReset,
+                ResetFlag::WindowWatchdog,
+                ResetFlag::LowPower
+            ]
+        );
+        assert_eq!(
+            reset_flags_from_codes("BOR,XYZ,POR".split(',')),
+            vec![ResetFlag::BrownOut, ResetFlag::PowerOn]
+        );
+        assert!(reset_flags_from_codes("".split(',')).is_empty());
+        assert_eq!(ResetFlag::WindowWatchdog.to_string(), "Window Watchdog");
+
+        let Ok(EventType::HubStatus(hub_status)) =
+            parse_event(&crate::test_common::get_hub_payload())
+        else {
+            panic!("Unable to parse hub status payload");
+        };
+        let hub = Hub::from(hub_status);
+        assert_eq!(
+            hub.reset_causes(),
+            vec![ResetFlag::BrownOut, ResetFlag::PinReset, ResetFlag::PowerOn]
+        );
+    }
+
+    #[test]
+    fn status_event_builders() {
+        let device = DeviceStatusEvent::builder("ST-00000512")
+            .voltage(2.41)
+            .rssi(-80)
+            .uptime(60)
+            .sensor_status(0b1000)
+            .build();
+
+        assert_eq!(device.get_serial_number(), "ST-00000512");
+        assert_eq!(device.get_battery_voltage(), 2.41);
+        assert_eq!(device.get_rssi(), -80);
+        assert_eq!(device.get_uptime(), 60);
+        assert!(device.get_sensor_status().pressure_failed);
+        assert!(!device.get_sensor_status().rh_failed);
+
+        let hub = HubStatusEvent::builder("HB-00000002")
+            .uptime(10)
+            .reset_flags("WDG")
+            .radio_stats(&[2, 0, 0, 1, 100])
+            .build();
+
+        assert_eq!(hub.get_serial_number(), "HB-00000002");
+        assert_eq!(hub.get_uptime(), 10);
+        assert_eq!(hub.get_reset_causes(), vec![ResetFlag::WatchdogReset]);
+        assert_eq!(hub.get_radio_status(), Ok(RadioStatus::RadioOn));
+    }
+
+    #[test]
+    fn timestamp_systemtime() {
+        use crate::test_common::*;
+
+        let at = |seconds| SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+
+        let parsed = |payload: Vec<u8>| parse_event(&payload).expect("Unable to parse payload");
+
+        let EventType::Rain(rain) = parsed(get_rain_payload()) else {
+            panic!("Expected a rain event");
+        };
+        assert_eq!(rain.timestamp_systemtime(), Ok(at(1493322445)));
+
+        let EventType::Lightning(lightning) = parsed(get_lightning_payload()) else {
+            panic!("Expected a lightning event");
+        };
+        assert_eq!(lightning.timestamp_systemtime(), Ok(at(1493322445)));
+
+        let EventType::RapidWind(rapid_wind) = parsed(get_rapidwind_payload()) else {
+            panic!("Expected a rapid wind event");
+        };
+        assert_eq!(rapid_wind.timestamp_systemtime(), Ok(at(1493322445)));
+
+        let EventType::Air(air) = parsed(get_air_payload()) else {
+            panic!("Expected an air event");
+        };
+        assert_eq!(air.timestamp_systemtime(), Ok(at(1493164835)));
+
+        let EventType::DeviceStatus(device) = parsed(get_device_payload()) else {
+            panic!("Expected a device status event");
+        };
+        assert_eq!(device.timestamp_systemtime(), at(1510855923));
+
+        let EventType::HubStatus(hub) = parsed(get_hub_payload()) else {
+            panic!("Expected a hub status event");
+        };
+        assert_eq!(hub.timestamp_systemtime(), at(1495724691));
+
+        // sky and station observations carry the timestamp as an f32, which rounds to the nearest 128 seconds
+        let within_rounding = |time: SystemTime, seconds| {
+            let expected = at(seconds);
+            let difference = time
+                .duration_since(expected)
+                .or_else(|_| expected.duration_since(time))
+                .expect("Unable to compare times");
+
+            difference <= Duration::from_secs(64)
+        };
+
+        let EventType::Sky(sky) = parsed(get_sky_payload()) else {
+            panic!("Expected a sky event");
+        };
+        let time = sky.timestamp_systemtime().expect("Expected a timestamp");
+        assert!(within_rounding(time, 1493321340));
+
+        let EventType::Observation(observation) = parsed(get_station_observation_payload()) else {
+            panic!("Expected a station observation event");
+        };
+        let time = observation
+            .timestamp_systemtime()
+            .expect("Expected a timestamp");
+        assert!(within_rounding(time, 1588948614));
+
+        let empty = RainStartEvent {
+            serial_number: "SK-00008453".to_string(),
+            r#type: "evt_precip".to_string(),
+            hub_sn: "HB-00000001".to_string(),
+            evt: vec![],
+        };
+        assert_eq!(empty.timestamp_systemtime(), Err(EventError::ParseError));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn timestamp_datetime() {
+        let hub = HubStatusEvent::builder("HB-00000001").build();
+
+        assert_eq!(hub.timestamp_datetime().timestamp(), 1495724691);
+        assert_eq!(
+            hub.timestamp_datetime().to_rfc3339(),
+            "2017-05-25T15:04:51+00:00"
+        );
+    }
+
+    #[test]
+    fn radio_status_decoders_agree() {
+        for code in 0..=7 {
+            let event = HubStatusEvent::builder("HB-00000001")
+                .radio_stats(&[2, 1, 0, code, 2839])
+                .build();
+
+            let decoded = event.get_radio_status();
+            let hub = Hub::from(event);
+
+            assert_eq!(decoded, Ok(hub.radio_stats.radio_status.clone()));
+        }
+
+        // codes outside the reference, such as 2, are unknown rather than guessed
+        assert_eq!(radio_status_from_u16(2), RadioStatus::Unknown);
+        assert_eq!(radio_status_from_u16(3), RadioStatus::RadioActive);
+    }
+
+    #[test]
+    fn observation_rows() {
+        let mut second = crate::test_common::STATION_OBSERVATION;
+        second[0] += 60.0;
+        second[7] = 21.5;
+        second[13] = 1.0;
+
+        let payload = serde_json::to_vec(&serde_json::json!({
+            "serial_number": "ST-00000512",
+            "type": "obs_st",
+            "hub_sn": "HB-00013030",
+            "obs": [crate::test_common::STATION_OBSERVATION, second],
+            "firmware_revision": 129
+        }))
+        .expect("Unable to serialize payload");
+
+        let Ok(EventType::Observation(observation)) = parse_event(&payload) else {
+            panic!("Unable to parse station observation payload");
+        };
+
+        let rows: Vec<ObservationRow> = observation.observations().collect();
+        assert_eq!(rows.len(), 2);
+
+        assert_eq!(rows[0].get_timestamp(), Ok(1588948614.0));
+        assert_eq!(rows[0].get_air_temperature(), Ok(22.37));
+        assert_eq!(rows[0].get_precip_type(), Ok(PrecipitationType::None));
+        assert_eq!(rows[1].get_timestamp(), Ok(1588948674.0));
+        assert_eq!(rows[1].get_air_temperature(), Ok(21.5));
+        assert_eq!(rows[1].get_precip_type(), Ok(PrecipitationType::Rain));
+        assert_eq!(rows[1].get_report_interval(), Ok(1.0));
+
+        // the event's getters read the first row
+        assert_eq!(observation.get_air_temperature(), Ok(22.37));
+
+        let short = ObservationRow {
+            obs: &[1588948614.0],
+        };
+        assert_eq!(short.get_wind_lull(), Err(EventError::ParseError));
+    }
+
+    #[test]
+    fn air_field_layout_by_firmware() {
+        let air = |firmware_revision: u16, obs: serde_json::Value| -> ObservationAirEvent {
+            serde_json::from_value(serde_json::json!({
+                "serial_number": "AR-00004049",
+                "type": "obs_air",
+                "hub_sn": "HB-00000001",
+                "obs": [obs],
+                "firmware_revision": firmware_revision
+            }))
+            .expect("Unable to build air event")
+        };
+
+        let documented = air(
+            17,
+            serde_json::json!([1493164835, 835.0, 10.0, 45, 2, 12, 3.46, 1]),
+        );
+        assert_eq!(documented.get_field_layout(), AirFieldLayout::V171);
+        assert_eq!(documented.get_lightning_count(), Ok(2.0));
+        assert_eq!(documented.get_lightning_avg_distance(), Ok(12.0));
+        assert_eq!(documented.get_battery_voltage(), Ok(3.46));
+        assert_eq!(documented.get_report_interval(), Ok(1.0));
+
+        let compact = air(
+            40,
+            serde_json::json!([1493164835, 835.0, 10.0, 45, 3.46, 1]),
+        );
+        assert_eq!(
+            compact.get_field_layout(),
+            AirFieldLayout::WITHOUT_LIGHTNING
+        );
+        assert_eq!(compact.get_air_temperature(), Ok(10.0));
+        assert_eq!(compact.get_lightning_count(), Err(EventError::ParseError));
+        assert_eq!(
+            compact.get_lightning_avg_distance(),
+            Err(EventError::ParseError)
+        );
+        assert_eq!(compact.get_battery_voltage(), Ok(3.46));
+        assert_eq!(compact.get_report_interval(), Ok(1.0));
+        assert_eq!(compact.validate(), Ok(()));
+
+        // documented firmware missing readings is malformed rather than reinterpreted
+        let truncated = air(
+            17,
+            serde_json::json!([1493164835, 835.0, 10.0, 45, 3.46, 1]),
+        );
+        assert_eq!(truncated.get_field_layout(), AirFieldLayout::V171);
+        assert_eq!(truncated.get_battery_voltage(), Err(EventError::ParseError));
+    }
+
+    #[test]
+    fn normalize_serial_numbers() {
+        assert_eq!(normalize_serial_number(" st-00000512\t"), "ST-00000512");
+        assert_eq!(normalize_serial_number("HB-00013030"), "HB-00013030");
+
+        let payload = crate::test_common::get_rapidwind_payload_from(
+            " st-00000512 ",
+            [1493322445.0, 2.3, 128.0],
+        );
+        let mut event = parse_event(&payload).expect("Unable to parse rapid wind payload");
+        event.normalize_serial_numbers();
+
+        let EventType::RapidWind(event) = event else {
+            panic!("Expected a rapid wind event");
+        };
+        assert_eq!(event.get_serial_number(), "ST-00000512");
+        assert_eq!(event.get_hub_sn(), "HB-00000001");
+    }
+
+    #[test]
+    fn station_and_hub_equality() {
+        let station = Station::from(observation_with_wind(5.0, 7.0));
+        let mut updated = station.clone();
+        assert_eq!(station, updated);
+
+        updated.wind_avg = Some(6.0);
+        assert_ne!(station, updated);
+        assert!(station.same_device(&updated));
+
+        let payload = crate::test_common::get_rapidwind_payload_from(
+            "ST-00000999",
+            [1493322445.0, 2.3, 128.0],
+        );
+        let EventType::RapidWind(wind) = parse_event(&payload).expect("Unable to parse payload")
+        else {
+            panic!("Expected a rapid wind event");
+        };
+        assert!(!station.same_device(&Station::from(wind)));
+
+        let EventType::HubStatus(hub_status) =
+            parse_event(&crate::test_common::get_hub_payload()).expect("Unable to parse payload")
+        else {
+            panic!("Expected a hub status event");
+        };
+        let hub = Hub::from(hub_status);
+        assert_eq!(hub, hub.clone());
+    }
+
+    #[test]
+    fn merge_station_records() {
+        let Ok(EventType::Observation(observation)) =
+            parse_event(&crate::test_common::get_station_observation_payload())
+        else {
+            panic!("Unable to parse station observation payload");
+        };
+        let Ok(EventType::RapidWind(wind)) =
+            parse_event(&crate::test_common::get_rapidwind_payload())
+        else {
+            panic!("Unable to parse rapid wind payload");
+        };
+
+        let mut station = Station::from(observation);
+        station.last_seen = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1588948614));
+
+        let mut windy = Station::from(wind);
+        windy.hub_sn = String::new();
+        windy.wind_direction = Some(200.0);
+        windy.last_seen = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1588948674));
+
+        station.merge(windy);
+
+        // the wind record was seen most recently, with the observation filling its gaps
+        assert_eq!(station.wind_direction, Some(200.0));
+        assert_eq!(station.hub_sn, "HB-00013030");
+        assert_eq!(station.air_temperature, Some(22.37));
+        assert!(station.observation.is_some());
+        assert!(station.wind_event.is_some());
+        assert_eq!(
+            station.last_seen,
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1588948674))
+        );
+    }
+
+    #[test]
+    fn is_precipitating() {
+        assert!(!PrecipitationType::None.is_precipitating());
+        assert!(PrecipitationType::Rain.is_precipitating());
+        assert!(PrecipitationType::Hail.is_precipitating());
+        assert!(PrecipitationType::RainHail.is_precipitating());
+
+        let mut station = Station::from(observation_with_wind(5.0, 7.0));
+        assert_eq!(station.is_precipitating(), Some(false));
+
+        station.precipitation_type = Some(PrecipitationType::Hail);
+        assert_eq!(station.is_precipitating(), Some(true));
+
+        station.precipitation_type = None;
+        assert_eq!(station.is_precipitating(), None);
+    }
+
+    #[test]
+    fn device_kinds() {
+        assert_eq!(
+            device_kind_from_serial("ST-00000512"),
+            Some(DeviceKind::Tempest)
+        );
+        assert_eq!(
+            device_kind_from_serial("AR-00004049"),
+            Some(DeviceKind::Air)
+        );
+        assert_eq!(
+            device_kind_from_serial(" sk-00008453"),
+            Some(DeviceKind::Sky)
+        );
+        assert_eq!(
+            device_kind_from_serial("HB-00013030"),
+            Some(DeviceKind::Hub)
+        );
+        assert_eq!(device_kind_from_serial("XX-00000001"), None);
+        assert_eq!(device_kind_from_serial("STATION"), None);
+        assert_eq!(device_kind_from_serial(""), None);
+
+        let station = Station::from(observation_with_wind(5.0, 7.0));
+        assert_eq!(station.device_kind(), Some(DeviceKind::Tempest));
     }
 }
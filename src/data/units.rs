@@ -0,0 +1,57 @@
+//! Conversions from the metric units reported by stations to imperial units
+
+/// Miles per hour in one meter per second
+pub const MPH_PER_MPS: f32 = 2.236_936;
+
+/// Inches of mercury in one millibar
+pub const INHG_PER_MB: f32 = 0.029_53;
+
+/// Miles in one kilometer
+pub const MILES_PER_KM: f32 = 0.621_371;
+
+/// Converts a temperature from degrees celsius to degrees fahrenheit
+pub fn celsius_to_fahrenheit(celsius: f32) -> f32 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// Converts a temperature from degrees fahrenheit to degrees celsius
+pub fn fahrenheit_to_celsius(fahrenheit: f32) -> f32 {
+    (fahrenheit - 32.0) * 5.0 / 9.0
+}
+
+/// Converts a speed from meters per second to miles per hour
+pub fn mps_to_mph(mps: f32) -> f32 {
+    mps * MPH_PER_MPS
+}
+
+/// Converts a pressure from millibars to inches of mercury
+pub fn mb_to_inhg(mb: f32) -> f32 {
+    mb * INHG_PER_MB
+}
+
+/// Converts a distance from kilometers to miles
+pub fn km_to_miles(km: f32) -> f32 {
+    km * MILES_PER_KM
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        assert_close(celsius_to_fahrenheit(22.37), 72.27);
+        assert_close(celsius_to_fahrenheit(-40.0), -40.0);
+        assert_close(fahrenheit_to_celsius(72.27), 22.37);
+        assert_close(mps_to_mph(10.0), 22.37);
+        assert_close(mb_to_inhg(1013.25), 29.92);
+        assert_close(km_to_miles(27.0), 16.78);
+    }
+}
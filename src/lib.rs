@@ -15,6 +15,7 @@
 //! ## References
 //! - [`WeatherFlow UDP`](https://weatherflow.github.io/Tempest/api/udp/v171/)
 
+pub mod clock;
 pub mod data;
 pub mod mock;
 pub mod test_common;
@@ -1,23 +1,204 @@
 //! Functions supporting a mock tempest device
 
-use std::net::UdpSocket;
+use crate::data::EventType;
+use crate::test_common::*;
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
+use std::path::Path;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time;
 
 pub struct MockSender {
     socket: UdpSocket,
+    localhost: IpAddr,
 }
 
 impl MockSender {
     /// Bind to localhost with system assigned port
     pub fn bind() -> Self {
-        let socket = UdpSocket::bind("127.0.0.1:0").expect("Unable to bind to address");
+        Self::bind_to(Ipv4Addr::LOCALHOST.into())
+    }
+
+    /// Bind to the IPv6 localhost with system assigned port
+    pub fn bind_v6() -> Self {
+        Self::bind_to(Ipv6Addr::LOCALHOST.into())
+    }
 
-        MockSender { socket }
+    fn bind_to(localhost: IpAddr) -> Self {
+        let socket =
+            UdpSocket::bind(SocketAddr::new(localhost, 0)).expect("Unable to bind to address");
+
+        MockSender { socket, localhost }
     }
 
     /// Send buffer to localhost with provided port
     pub fn send(&self, buffer: Vec<u8>, port: u16) {
+        self.send_to(buffer, self.localhost, port);
+    }
+
+    /// Serialize an event to its UDP packet and send it to localhost with provided port
+    pub fn send_event(&self, event: &EventType, port: u16) {
+        let buffer = serde_json::to_vec(event).expect("Unable to serialize event");
+
+        self.send(buffer, port);
+    }
+
+    /// Send buffer to the provided address and port, such as a multicast group
+    pub fn send_to(&self, buffer: Vec<u8>, address: IpAddr, port: u16) {
         self.socket
-            .send_to(&buffer, format!("127.0.0.1:{port}"))
+            .send_to(&buffer, SocketAddr::new(address, port))
             .expect("couldn't send data");
     }
 }
+
+/// Serial numbers and intervals of the events emitted by a `MockHub`
+///
+/// Defaults to a hub and station using the serial numbers of the `test_common` payloads, with each event type
+/// sent about as often as a real hub sends it.
+#[derive(Debug, Clone)]
+pub struct MockHubConfig {
+    pub station_serial_number: String,
+    pub hub_serial_number: String,
+    pub rapid_wind: Duration,
+    pub observation: Duration,
+    pub device_status: Duration,
+    pub hub_status: Duration,
+    /// Interval between lightning strikes, or None for no lightning
+    pub lightning: Option<Duration>,
+}
+
+impl Default for MockHubConfig {
+    fn default() -> Self {
+        Self {
+            station_serial_number: "ST-00000512".to_string(),
+            hub_serial_number: "HB-00013030".to_string(),
+            rapid_wind: Duration::from_secs(3),
+            observation: Duration::from_secs(60),
+            device_status: Duration::from_secs(60),
+            hub_status: Duration::from_secs(10),
+            lightning: Some(Duration::from_secs(300)),
+        }
+    }
+}
+
+/// Mock hub sending the `test_common` payloads to localhost on a schedule until dropped
+pub struct MockHub {
+    task: JoinHandle<()>,
+}
+
+impl MockHub {
+    /// Start sending events to localhost with provided port, must be called from within a Tokio runtime
+    ///
+    /// Every event type is sent once immediately and then at its configured interval.
+    pub fn spawn(port: u16, config: MockHubConfig) -> Self {
+        let sender = MockSender::bind();
+
+        let station = config.station_serial_number.as_str();
+        let hub = config.hub_serial_number.as_str();
+        let rapid_wind_payload = with_serial_numbers(get_rapidwind_payload(), station, hub);
+        let observation_payload =
+            with_serial_numbers(get_station_observation_payload(), station, hub);
+        let device_payload = with_serial_numbers(get_device_payload(), station, hub);
+        let hub_payload = with_serial_numbers(get_hub_payload(), station, hub);
+        let lightning_payload = with_serial_numbers(get_lightning_payload(), station, hub);
+
+        let mut rapid_wind = time::interval(config.rapid_wind);
+        let mut observation = time::interval(config.observation);
+        let mut device_status = time::interval(config.device_status);
+        let mut hub_status = time::interval(config.hub_status);
+        let mut lightning = config.lightning.map(time::interval);
+
+        let task = tokio::spawn(async move {
+            loop {
+                let payload = tokio::select! {
+                    _ = rapid_wind.tick() => &rapid_wind_payload,
+                    _ = observation.tick() => &observation_payload,
+                    _ = device_status.tick() => &device_payload,
+                    _ = hub_status.tick() => &hub_payload,
+                    _ = async {
+                        match lightning.as_mut() {
+                            Some(lightning) => lightning.tick().await,
+                            None => std::future::pending().await,
+                        }
+                    } => &lightning_payload,
+                };
+
+                sender.send(payload.clone(), port);
+            }
+        });
+
+        MockHub { task }
+    }
+}
+
+impl Drop for MockHub {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Replay a capture of newline-delimited JSON packets to localhost with provided port, returning the number sent
+///
+/// Packets are sent in the order they appear, skipping blank lines, and need not be valid events. When consecutive
+/// packets both carry a timestamp the gap between them is waited before sending, divided by `speed` so that 2.0
+/// replays twice as fast. A `speed` of zero or below, or packets without a timestamp, are sent without waiting.
+/// Packet captures (pcap) are not supported.
+///
+/// Returns an `Err` if the file cannot be read.
+pub async fn replay_file(path: impl AsRef<Path>, port: u16, speed: f64) -> io::Result<usize> {
+    let capture = fs::read_to_string(path)?;
+    let sender = MockSender::bind();
+
+    let mut previous: Option<f64> = None;
+    let mut sent = 0;
+
+    for line in capture
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        let timestamp = serde_json::from_str(line)
+            .ok()
+            .as_ref()
+            .and_then(packet_timestamp);
+
+        if let (Some(previous), Some(timestamp)) = (previous, timestamp)
+            && speed > 0.0
+            && timestamp > previous
+        {
+            time::sleep(Duration::from_secs_f64((timestamp - previous) / speed)).await;
+        }
+
+        sender.send(line.as_bytes().to_vec(), port);
+        previous = timestamp.or(previous);
+        sent += 1;
+    }
+
+    Ok(sent)
+}
+
+/// Epoch timestamp (s, seconds) of a packet from wherever its event type reports it
+fn packet_timestamp(json: &Value) -> Option<f64> {
+    json["timestamp"]
+        .as_f64()
+        .or_else(|| json["ob"][0].as_f64())
+        .or_else(|| json["evt"][0].as_f64())
+        .or_else(|| json["obs"][0][0].as_f64())
+}
+
+/// Replace the serial numbers of a payload, where a hub status carries the hub's and other events the station's
+fn with_serial_numbers(payload: Vec<u8>, station: &str, hub: &str) -> Vec<u8> {
+    let mut json: Value = serde_json::from_slice(&payload).expect("Unable to parse payload");
+
+    if json["type"] == "hub_status" {
+        json["serial_number"] = hub.into();
+    } else {
+        json["serial_number"] = station.into();
+        json["hub_sn"] = hub.into();
+    }
+
+    serde_json::to_vec(&json).expect("Unable to serialize payload")
+}
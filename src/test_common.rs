@@ -13,6 +13,17 @@ pub fn get_lightning_payload() -> Vec<u8> {
     .expect("Failed to convert JSON to vector")
 }
 
+pub fn get_lightning_payload_from(serial_number: &str, evt: [u64; 3]) -> Vec<u8> {
+    serde_json::to_vec(&json!(
+    {
+        "serial_number": serial_number,
+        "type":"evt_strike",
+        "hub_sn": "HB-00000001",
+        "evt": evt
+      }))
+    .expect("Failed to convert JSON to vector")
+}
+
 pub fn get_hub_payload() -> Vec<u8> {
     serde_json::to_vec(&json!(
     {
@@ -31,6 +42,24 @@ pub fn get_hub_payload() -> Vec<u8> {
     .expect("Failured to convert JSON to vector")
 }
 
+pub fn get_hub_payload_from(serial_number: &str, timestamp: u64, radio_stats: [u16; 5]) -> Vec<u8> {
+    serde_json::to_vec(&json!(
+    {
+      "serial_number": serial_number,
+      "type":"hub_status",
+      "firmware_revision":"35",
+      "uptime":1670133,
+      "rssi":-62,
+      "timestamp": timestamp,
+      "reset_flags": "BOR,PIN,POR",
+      "seq": 48,
+      "fs": [1, 0, 15675411, 524288],
+          "radio_stats": radio_stats,
+          "mqtt_stats": [1, 0]
+    }))
+    .expect("Failed to convert JSON to vector")
+}
+
 pub fn get_device_payload() -> Vec<u8> {
     serde_json::to_vec(&json!(
     {
@@ -49,6 +78,24 @@ pub fn get_device_payload() -> Vec<u8> {
     .expect("Failed to convert JSON to vector")
 }
 
+pub fn get_secondary_device_payload() -> Vec<u8> {
+    serde_json::to_vec(&json!(
+    {
+            "serial_number": "AR-00004050",
+            "type": "device_status",
+            "hub_sn": "HB-00000001",
+            "timestamp": 1510855923,
+            "uptime": 2189,
+            "voltage": 3.50,
+            "firmware_revision": 17,
+            "rssi": -72,
+            "hub_rssi": -87,
+            "sensor_status": 0,
+            "debug": 0
+    }))
+    .expect("Failed to convert JSON to vector")
+}
+
 pub fn get_station_observation_payload() -> Vec<u8> {
     serde_json::to_vec(&json!(
     {
@@ -63,6 +110,39 @@ pub fn get_station_observation_payload() -> Vec<u8> {
     .expect("Failured to convert JSON to vector")
 }
 
+pub const STATION_OBSERVATION: [f64; 18] = [
+    1588948614.0,
+    0.18,
+    0.22,
+    0.27,
+    144.0,
+    6.0,
+    1017.57,
+    22.37,
+    50.26,
+    328.0,
+    0.03,
+    3.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    2.410,
+    1.0,
+];
+
+pub fn get_station_observation_payload_from(serial_number: &str, obs: [f64; 18]) -> Vec<u8> {
+    serde_json::to_vec(&json!(
+    {
+        "serial_number": serial_number,
+        "type": "obs_st",
+        "hub_sn": "HB-00013030",
+        "obs": [obs],
+        "firmware_revision": 129
+    }))
+    .expect("Failed to convert JSON to vector")
+}
+
 pub fn get_secondary_station_observation_payload() -> Vec<u8> {
     serde_json::to_vec(&json!(
     {
@@ -99,6 +179,17 @@ pub fn get_rapidwind_payload() -> Vec<u8> {
     .expect("Failed to convert JSON to vector")
 }
 
+pub fn get_rapidwind_payload_from(serial_number: &str, ob: [f64; 3]) -> Vec<u8> {
+    serde_json::to_vec(&json!(
+    {
+        "serial_number": serial_number,
+        "type":"rapid_wind",
+        "hub_sn": "HB-00000001",
+        "ob": ob
+      }))
+    .expect("Failed to convert JSON to vector")
+}
+
 pub fn get_air_payload() -> Vec<u8> {
     serde_json::to_vec(&json!(
     {
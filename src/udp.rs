@@ -1,12 +1,21 @@
 //! Primary interface for WeatherFlow Tempest weather data over UDP
 
+use crate::clock::{Clock, SystemClock};
 use crate::data::*;
-use log::trace;
-use serde_json::{Error, Value};
-use std::net::Ipv4Addr;
-use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use log::{debug, error, trace, warn};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::net::UdpSocket;
-use tokio::sync::{mpsc, mpsc::Receiver};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{Notify, broadcast, mpsc, mpsc::Receiver};
+use tokio::task::JoinHandle;
+use tokio::time::{self, Instant};
+use tokio_stream::wrappers::ReceiverStream;
 
 /// Default Tempest UDP port
 const DEFAULT_PORT: u16 = 50222;
@@ -14,825 +23,4505 @@ const DEFAULT_PORT: u16 = 50222;
 /// Default UDP buffer sized used in this crate
 const DEFAULT_BUFFER_SIZE: usize = 4096;
 
+/// Largest possible UDP payload over IPv4, beyond which a larger buffer is never filled
+const MAX_BUFFER_SIZE: usize = 65507;
+
+/// Number of events queued for the channel receiver before the overflow policy applies
+const DEFAULT_CHANNEL_CAPACITY: usize = 16;
+
+/// Number of events retained for broadcast subscribers that have yet to receive them
+const DEFAULT_BROADCAST_CAPACITY: usize = 64;
+
+/// Time over which repeated updates to the cache are coalesced into a single notification
+const CACHE_UPDATE_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Time without precipitation before it is considered to have stopped, so brief gaps do not flicker
+const PRECIP_STOPPED_DEBOUNCE: Duration = Duration::from_secs(60);
+
+/// Policy applied to new events when the channel receiver falls behind and its queue is full
+///
+/// `Block` suits consumers that need every event, such as those recording to storage, at the cost of pausing the
+/// listener (caching and broadcast subscribers included) until the receiver catches up. `DropOldest` suits real-time consumers such as dashboards,
+/// which always catch up on the most recent events. `DropNewest` keeps the backlog intact and discards new arrivals.
+/// Dropped events are counted by `Tempest::dropped_event_count` and are still shared with broadcast subscribers.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum OverflowPolicy {
+    /// Wait for the receiver to make room, stalling delivery to broadcast subscribers meanwhile
+    #[default]
+    Block,
+    /// Discard the new event, keeping the queued events
+    DropNewest,
+    /// Discard the oldest queued event to make room for the new event
+    DropOldest,
+}
+
+/// Serial number an event is matched against when filtering the events shared on the channel
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum FilterBy {
+    /// Match the serial number of the device that sent the event
+    #[default]
+    Device,
+    /// Match the serial number of the hub the event was reported through, a hub status event matching its own
+    Hub,
+}
+
+/// Snapshot of the listener's counters, such as for exporting to a metrics system
+///
+/// Counters start from zero when the listener is bound and are never reset.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metrics {
+    /// Packets received on the socket
+    pub packets_received: u64,
+    /// Packets that were not JSON or did not match the layout of their event type
+    pub parse_errors: u64,
+    /// Packets of an event type this crate does not support
+    pub unknown_events: u64,
+    /// Packets that filled the receive buffer and may have been truncated
+    pub truncated_packets: u64,
+    /// Events that failed validation
+    pub rejected_events: u64,
+    /// Events dropped from the channel receiver by the overflow policy
+    pub dropped_events: u64,
+    /// Events parsed keyed by their type tag, such as `obs_st`
+    pub events: HashMap<String, u64>,
+}
+
+/// Handler invoked with the type tag and raw JSON of an event type this crate does not support
+pub type UnknownEventHandler = Arc<dyn Fn(&str, &Value) + Send + Sync>;
+
+/// Handler invoked with each event before it is shared, registered with `Tempest::on_event`
+pub type EventHandler = Arc<dyn Fn(&EventType) + Send + Sync>;
+
 /// Inner data structure of `Tempest` containing cached hubs and stations
 #[derive(Clone)]
 pub struct Inner {
-    hubs_cached: Vec<Hub>,
-    stations_cached: Vec<Station>,
+    /// Cached hubs keyed by serial number
+    hubs_cached: HashMap<String, Hub>,
+    /// Cached stations keyed by serial number
+    stations_cached: HashMap<String, Station>,
+    /// Keep the cached wind direction when a calm rapid wind event arrives
+    hold_calm_wind_direction: bool,
+    /// Stop sharing events that fail validation on the channel
+    drop_invalid_events: bool,
+    /// Number of events that failed validation
+    rejected_events: u64,
+    /// Fallback handler for unsupported event types
+    unknown_handler: Option<UnknownEventHandler>,
+    /// Handlers invoked with each event before it is shared
+    event_handlers: Vec<EventHandler>,
+    /// Source of the current time for time-dependent features
+    clock: Arc<dyn Clock>,
+    /// Keep the raw JSON of the last packet received from each device
+    store_raw_json: bool,
+    /// Raw JSON of the last packet received keyed by device serial number
+    raw_json: HashMap<String, String>,
+    /// Size of the buffer each UDP packet is received into
+    buffer_size: usize,
+    /// Number of packets that filled the receive buffer and may have been truncated
+    truncated_packets: u64,
+    /// Local time of the last precipitation reported by each station that is currently precipitating
+    precipitating: HashMap<String, SystemTime>,
+    /// Number of events queued for the channel receiver
+    channel_capacity: usize,
+    /// Policy applied when the channel receiver's queue is full
+    overflow_policy: OverflowPolicy,
+    /// Number of events dropped by the overflow policy
+    dropped_events: u64,
+    /// Number of rapid wind samples retained per station
+    wind_history_capacity: usize,
+    /// Weight of each new reading in the smoothed air temperature
+    air_temperature_smoothing: f32,
+    /// Number of packets received
+    packets_received: u64,
+    /// Number of packets that could not be parsed
+    parse_errors: u64,
+    /// Number of packets of unsupported event types
+    unknown_events: u64,
+    /// Number of events parsed keyed by type tag
+    event_counts: HashMap<String, u64>,
+    /// Serial numbers of the events shared on the channel, every event is shared when None
+    station_filter: Option<Vec<String>>,
+    /// Serial number events are matched against by the station filter
+    filter_by: FilterBy,
 }
 
 impl Inner {
     fn new() -> Self {
         Inner {
-            hubs_cached: Vec::new(),
-            stations_cached: Vec::new(),
+            hubs_cached: HashMap::new(),
+            stations_cached: HashMap::new(),
+            hold_calm_wind_direction: false,
+            drop_invalid_events: false,
+            rejected_events: 0,
+            unknown_handler: None,
+            event_handlers: Vec::new(),
+            clock: Arc::new(SystemClock),
+            store_raw_json: false,
+            raw_json: HashMap::new(),
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            truncated_packets: 0,
+            precipitating: HashMap::new(),
+            channel_capacity: DEFAULT_CHANNEL_CAPACITY,
+            overflow_policy: OverflowPolicy::Block,
+            dropped_events: 0,
+            wind_history_capacity: WIND_HISTORY_CAPACITY,
+            air_temperature_smoothing: DEFAULT_SMOOTHING_ALPHA,
+            packets_received: 0,
+            parse_errors: 0,
+            unknown_events: 0,
+            event_counts: HashMap::new(),
+            station_filter: None,
+            filter_by: FilterBy::Device,
         }
     }
 }
 
-/// Tempest hub and station interface
-#[derive(Clone)]
-pub struct Tempest {
-    /// Thread safe receiver for UDP socket data
-    recv: Arc<UdpSocket>,
-    /// Thread safe read-write lock on inner data (cached data)
-    inner: Arc<RwLock<Inner>>,
+/// Builder for configuring and starting a UDP listener
+///
+/// Unless configured otherwise the listener binds to `0.0.0.0:50222`, does not cache events,
+/// and shares events from every station.
+#[derive(Default)]
+pub struct TempestBuilder {
+    address: Option<IpAddr>,
+    port: Option<u16>,
+    caching: bool,
+    station_filter: Option<Vec<String>>,
+    hold_calm_wind_direction: bool,
+    drop_invalid_events: bool,
+    unknown_handler: Option<UnknownEventHandler>,
+    clock: Option<Arc<dyn Clock>>,
+    store_raw_json: bool,
+    multicast: Option<(Ipv4Addr, Ipv4Addr)>,
+    buffer_size: Option<usize>,
+    channel_capacity: Option<usize>,
+    overflow_policy: OverflowPolicy,
+    filter_by: FilterBy,
+    wind_history_capacity: Option<usize>,
+    air_temperature_smoothing: Option<f32>,
 }
 
-impl Tempest {
-    async fn bind(ip: Option<Ipv4Addr>, port: Option<u16>) -> Self {
-        let ip = ip.unwrap_or(Ipv4Addr::new(0, 0, 0, 0));
-        let port = port.unwrap_or(DEFAULT_PORT);
+impl TempestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        let sock = UdpSocket::bind(format!("{ip}:{port}"))
-            .await
-            .expect("Error binding to socket");
-        let arc_socket = Arc::new(sock);
+    /// Address of the interface to listen on, either IPv4 or IPv6
+    pub fn address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = Some(address.into());
+        self
+    }
 
-        Self {
-            recv: arc_socket,
-            inner: Arc::new(RwLock::new(Inner::new())),
-        }
+    /// Port to listen on, where 0 lets the system assign one
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
     }
 
-    /// Grabs the shared read lock of the inner
-    fn read_inner(&self) -> RwLockReadGuard<'_, Inner> {
-        self.inner.read().expect("Unable to acquire read lock")
+    /// Cache data about hubs and stations reporting events
+    pub fn cache(mut self, caching: bool) -> Self {
+        self.caching = caching;
+        self
     }
 
-    /// Grabs the exclusive write lock of the inner
-    fn write_inner(&self) -> RwLockWriteGuard<'_, Inner> {
-        self.inner.write().expect("Unable to acquire write lock")
+    /// Only share events that match the provided serial numbers, compared in their normalized form
+    pub fn filter(mut self, station_filter: Vec<&str>) -> Self {
+        self.station_filter = Some(
+            station_filter
+                .iter()
+                .map(|&station| normalize_serial_number(station))
+                .collect(),
+        );
+        self
     }
 
-    /// Returns a count of the number of stations cached
-    pub fn station_count(&self) -> usize {
-        self.read_inner().stations_cached.len()
+    /// Serial number matched against the `filter`, the sending device's unless configured otherwise
+    pub fn filter_by(mut self, filter_by: FilterBy) -> Self {
+        self.filter_by = filter_by;
+        self
     }
 
-    /// Returns a count of the number of hubs cached
-    pub fn hub_count(&self) -> usize {
-        self.read_inner().hubs_cached.len()
+    /// Update the cached wind direction from rapid wind events, keeping the last direction when a calm
+    /// event (no speed or direction) arrives rather than snapping it to north
+    ///
+    /// Without this the cached wind direction is only taken from observations.
+    pub fn hold_calm_wind_direction(mut self, hold: bool) -> Self {
+        self.hold_calm_wind_direction = hold;
+        self
     }
 
-    /// Insert or replace the provided hub into the hub cache
-    fn hub_upsert(&mut self, hub_data: Hub) {
-        let index = self.get_hub_index(&hub_data.serial_number);
+    /// Stop sharing events that fail validation rather than forwarding them on the channel
+    ///
+    /// Invalid events are never cached regardless of this setting.
+    pub fn drop_invalid(mut self, drop: bool) -> Self {
+        self.drop_invalid_events = drop;
+        self
+    }
 
-        if let Some(index) = index {
-            trace!("Removing existing hub record");
-            self.write_inner().hubs_cached.swap_remove(index);
-        }
+    /// Register a handler for event types this crate does not support, called with the type tag and raw JSON
+    ///
+    /// The handler is called before the event is shared as an `EventType::Unknown`.
+    pub fn unknown_handler(mut self, handler: UnknownEventHandler) -> Self {
+        self.unknown_handler = Some(handler);
+        self
+    }
 
-        self.write_inner().hubs_cached.push(hub_data);
+    /// Source of the current time for time-dependent features such as staleness and arrival timing,
+    /// the system clock is used unless configured otherwise
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
     }
 
-    /// Cache a ObservationEvent into the station cache
-    fn cache_station_observation(&mut self, observation: ObservationEvent) {
-        let index = self.get_station_index(&observation.get_serial_number());
+    /// Keep the raw JSON of the last packet received from each device for retrieval with `Tempest::last_raw_json`
+    pub fn store_raw_json(mut self, store: bool) -> Self {
+        self.store_raw_json = store;
+        self
+    }
 
-        if let Some(index) = index {
-            // general station info
-            self.write_inner().stations_cached[index].firmware_revision =
-                Some(observation.get_firmware_revision());
+    /// Size (bytes) of the buffer each UDP packet is received into, 4096 unless configured otherwise and clamped to
+    /// 1 to 65507
+    ///
+    /// Packets larger than the buffer are truncated and fail to parse, which is logged as a warning and counted by
+    /// `Tempest::truncated_packet_count`. Tempest packets are typically well under 1024 bytes, so the default leaves
+    /// ample headroom for new firmware fields. Sizes below 1024 bytes are not recommended, and there is no benefit
+    /// beyond 65507 bytes, the largest possible UDP payload.
+    pub fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = Some(buffer_size);
+        self
+    }
 
-            self.write_inner().stations_cached[index].hub_sn = observation.get_hub_sn();
+    /// Number of events queued for the channel receiver before the overflow policy applies, 16 unless configured
+    /// otherwise and at least 1
+    pub fn channel_capacity(mut self, capacity: usize) -> Self {
+        self.channel_capacity = Some(capacity);
+        self
+    }
 
-            self.write_inner().stations_cached[index].serial_number =
-                observation.get_serial_number();
+    /// Policy applied to new events once the channel receiver's queue is full, blocking unless configured otherwise
+    pub fn overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.overflow_policy = policy;
+        self
+    }
 
-            self.write_inner().stations_cached[index].battery_voltage =
-                observation.get_battery_voltage().ok();
+    /// Number of rapid wind samples retained per station for `Tempest::wind_history` and `Tempest::gust_windows`,
+    /// 360 (about 18 minutes) unless configured otherwise and at least 1
+    ///
+    /// The ten minute gust window needs at least 200 samples to cover its full span.
+    pub fn wind_history_capacity(mut self, capacity: usize) -> Self {
+        self.wind_history_capacity = Some(capacity);
+        self
+    }
 
-            // common weather data
-            self.write_inner().stations_cached[index].station_pressure =
-                observation.get_station_pressure().ok();
+    /// Weight of each new reading in the smoothed air temperature returned by `Tempest::get_air_temperature_smoothed`,
+    /// 0.2 unless configured otherwise and clamped to 0.0 to 1.0
+    ///
+    /// Smaller values produce a smoother but more lagging temperature, while 1.0 disables smoothing.
+    pub fn air_temperature_smoothing(mut self, alpha: f32) -> Self {
+        self.air_temperature_smoothing = Some(alpha);
+        self
+    }
 
-            self.write_inner().stations_cached[index].air_temperature =
-                observation.get_air_temperature().ok();
+    /// Join the multicast `group` on the interface with the provided address once bound, for stations sending
+    /// multicast rather than broadcast UDP packets
+    ///
+    /// The listener should be bound to `0.0.0.0` (the default) to receive packets addressed to the group.
+    pub fn multicast(mut self, group: Ipv4Addr, interface: Ipv4Addr) -> Self {
+        self.multicast = Some((group, interface));
+        self
+    }
+
+    /// Bind to the configured address and start listening for UDP packets
+    ///
+    /// Returns a `Tempest` instance along with a Tokio receiver containing a weather event as an `EventType`.
+    /// The listener stops and releases its socket once every clone of the `Tempest` instance is dropped.
+    ///
+    /// Returns an `Err` if the socket cannot be bound, such as when the port is already in use.
+    pub async fn listen(self) -> io::Result<(Tempest, Receiver<EventType>)> {
+        let tempest = Tempest::bind(self.address, self.port, self.multicast).await?;
+
+        {
+            let mut inner = tempest.write_inner();
+            inner.hold_calm_wind_direction = self.hold_calm_wind_direction;
+            inner.drop_invalid_events = self.drop_invalid_events;
+            inner.unknown_handler = self.unknown_handler;
+            inner.store_raw_json = self.store_raw_json;
+
+            if let Some(clock) = self.clock {
+                inner.clock = clock;
+            }
 
-            self.write_inner().stations_cached[index].relative_humidity = observation.get_rh().ok();
+            if let Some(buffer_size) = self.buffer_size {
+                inner.buffer_size = buffer_size.clamp(1, MAX_BUFFER_SIZE);
+            }
 
-            self.write_inner().stations_cached[index].lightning_strike_count =
-                observation.get_lightning_strike_count().ok();
+            if let Some(capacity) = self.channel_capacity {
+                inner.channel_capacity = capacity.max(1);
+            }
 
-            self.write_inner().stations_cached[index].lightning_strike_avg_distance =
-                observation.get_lightning_avg_distance().ok();
+            inner.overflow_policy = self.overflow_policy;
+            inner.station_filter = self.station_filter;
+            inner.filter_by = self.filter_by;
 
-            self.write_inner().stations_cached[index].illuminance =
-                observation.get_illuminance().ok();
+            if let Some(capacity) = self.wind_history_capacity {
+                inner.wind_history_capacity = capacity.max(1);
+            }
 
-            self.write_inner().stations_cached[index].uv = observation.get_uv().ok();
+            if let Some(alpha) = self.air_temperature_smoothing {
+                inner.air_temperature_smoothing = alpha.clamp(0.0, 1.0);
+            }
+        }
 
-            self.write_inner().stations_cached[index].rain_amount_prev_minute =
-                observation.get_rain_amount_prev_min().ok();
+        Ok(tempest.spawn_listener(self.caching))
+    }
+}
 
-            self.write_inner().stations_cached[index].wind_lull = observation.get_wind_lull().ok();
+/// Queue of events for the channel receiver which discards its oldest event when full
+struct EventRing {
+    events: Mutex<VecDeque<EventType>>,
+    capacity: usize,
+    available: Notify,
+    closed: AtomicBool,
+}
 
-            self.write_inner().stations_cached[index].wind_avg = observation.get_wind_avg().ok();
+impl EventRing {
+    /// Queue an event, returning true if the oldest event was discarded to make room
+    fn push(&self, event: EventType) -> bool {
+        let mut events = self
+            .events
+            .lock()
+            .expect("Unable to acquire event queue lock");
 
-            self.write_inner().stations_cached[index].wind_gust = observation.get_wind_gust().ok();
+        let dropped = events.len() >= self.capacity;
 
-            self.write_inner().stations_cached[index].wind_direction =
-                observation.get_wind_direction().ok();
+        if dropped {
+            trace!("Dropping oldest queued event");
+            events.pop_front();
+        }
 
-            self.write_inner().stations_cached[index].solar_radiation =
-                observation.get_solar_radiation().ok();
+        events.push_back(event);
+        self.available.notify_one();
 
-            self.write_inner().stations_cached[index].precipitation_type =
-                observation.get_precip_type().ok();
+        dropped
+    }
 
-            // cache event
-            self.write_inner().stations_cached[index]
-                .observation
-                .replace(observation);
-        } else {
-            self.write_inner().stations_cached.push(observation.into());
+    /// Hand queued events over to the channel one at a time until the queue is closed and empty, or the receiver is dropped
+    async fn forward(self: Arc<Self>, tx: mpsc::Sender<EventType>) {
+        loop {
+            let next = self
+                .events
+                .lock()
+                .expect("Unable to acquire event queue lock")
+                .pop_front();
+
+            match next {
+                Some(event) => {
+                    if tx.send(event).await.is_err() {
+                        return;
+                    }
+                }
+                None if self.closed.load(Ordering::Acquire) => return,
+                None => {
+                    tokio::select! {
+                        _ = self.available.notified() => {}
+                        _ = tx.closed() => return,
+                    }
+                }
+            }
         }
     }
+}
 
-    /// Cache a RapidWindEvent into the station cache
-    fn cache_station_wind_event(&mut self, event: RapidWindEvent) {
-        let index = self.get_station_index(&event.get_serial_number());
+/// Sending half of an `EventRing` which closes the queue when dropped
+struct RingSender(Arc<EventRing>);
 
-        if let Some(index) = index {
-            self.write_inner().stations_cached[index]
-                .wind_event
-                .replace(event);
-        } else {
-            self.write_inner().stations_cached.push(event.into());
-        }
+impl Drop for RingSender {
+    fn drop(&mut self) {
+        self.0.closed.store(true, Ordering::Release);
+        self.0.available.notify_one();
     }
+}
 
-    /// Cache a RainStartEvent into the station cache
-    fn cache_station_rain_event(&mut self, event: RainStartEvent) {
-        let index = self.get_station_index(&event.get_serial_number());
+/// Sending half of the channel receiver applying the configured overflow policy
+enum EventSink {
+    Block(mpsc::Sender<EventType>),
+    DropNewest(mpsc::Sender<EventType>),
+    DropOldest(RingSender),
+}
 
-        if let Some(index) = index {
-            self.write_inner().stations_cached[index]
-                .rain_event
-                .replace(event);
-        } else {
-            self.write_inner().stations_cached.push(event.into());
+impl EventSink {
+    /// Create the sink along with the channel receiver, must be called from within a Tokio runtime
+    fn new(policy: OverflowPolicy, capacity: usize) -> (Self, Receiver<EventType>) {
+        match policy {
+            OverflowPolicy::Block => {
+                let (tx, rx) = mpsc::channel(capacity);
+                (EventSink::Block(tx), rx)
+            }
+            OverflowPolicy::DropNewest => {
+                let (tx, rx) = mpsc::channel(capacity);
+                (EventSink::DropNewest(tx), rx)
+            }
+            OverflowPolicy::DropOldest => {
+                // the ring holds the queue as a channel cannot discard its oldest event
+                let (tx, rx) = mpsc::channel(1);
+                let ring = Arc::new(EventRing {
+                    events: Mutex::new(VecDeque::with_capacity(capacity)),
+                    capacity,
+                    available: Notify::new(),
+                    closed: AtomicBool::new(false),
+                });
+
+                tokio::spawn(ring.clone().forward(tx));
+
+                (EventSink::DropOldest(RingSender(ring)), rx)
+            }
         }
     }
 
-    /// Cache a LightningStrikeEvent into the station cache
-    fn cache_station_lightning_event(&mut self, event: LightningStrikeEvent) {
-        let index = self.get_station_index(&event.get_serial_number());
-
-        if let Some(index) = index {
-            self.write_inner().stations_cached[index]
-                .lightning_event
-                .replace(event);
-        } else {
-            self.write_inner().stations_cached.push(event.into());
+    /// Send an event to the channel receiver if it is still open, returning true if an event was dropped
+    async fn send(&self, event: EventType) -> bool {
+        match self {
+            EventSink::Block(tx) => {
+                if !tx.is_closed() {
+                    let _ = tx
+                        .send(event)
+                        .await
+                        .inspect_err(|e| warn!("Unable to send {e:?}"));
+                }
+
+                false
+            }
+            EventSink::DropNewest(tx) => match tx.try_send(event) {
+                Ok(()) | Err(TrySendError::Closed(_)) => false,
+                Err(TrySendError::Full(_)) => {
+                    trace!("Dropping newest event");
+                    true
+                }
+            },
+            EventSink::DropOldest(ring) => ring.0.push(event),
         }
     }
+}
 
-    /// Cache a ObservationAirEvent into the station cache
-    fn cache_station_air_event(&mut self, event: ObservationAirEvent) {
-        let index = self.get_station_index(&event.get_serial_number());
-
-        if let Some(index) = index {
-            // general station info
-            self.write_inner().stations_cached[index].serial_number = event.get_serial_number();
+/// Handle to the background listener task which signals the task to stop when dropped
+pub struct ListenHandle {
+    shutdown: Option<Arc<Notify>>,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
 
-            self.write_inner().stations_cached[index].hub_sn = event.get_hub_sn();
+impl ListenHandle {
+    /// Stop signalling the listener task on drop, leaving it running for the lifetime of the process
+    fn detach(&mut self) {
+        self.shutdown = None;
+    }
+}
 
-            self.write_inner().stations_cached[index].firmware_revision =
-                Some(event.get_firmware_revision());
+impl Drop for ListenHandle {
+    fn drop(&mut self) {
+        if let Some(shutdown) = &self.shutdown {
+            trace!("Signalling listener task to stop");
+            shutdown.notify_one();
+        }
+    }
+}
 
-            self.write_inner().stations_cached[index].battery_voltage =
-                event.get_battery_voltage().ok();
+/// Tempest hub and station interface
+#[derive(Clone)]
+pub struct Tempest {
+    /// Thread safe receiver for UDP socket data
+    recv: Arc<UdpSocket>,
+    /// Thread safe read-write lock on inner data (cached data)
+    inner: Arc<RwLock<Inner>>,
+    /// Listener task handle shared by every clone handed out to the user
+    handle: Option<Arc<ListenHandle>>,
+    /// Sender sharing every event with broadcast subscribers
+    events: broadcast::Sender<EventType>,
+    /// Sender sharing the serial number of every station whose cache is updated
+    cache_updates: broadcast::Sender<String>,
+    /// Sender sharing the onset and cessation of precipitation at every station
+    precip_transitions: broadcast::Sender<PrecipTransition>,
+}
 
-            // common weather data
-            self.write_inner().stations_cached[index].station_pressure =
-                event.get_station_pressure().ok();
+impl Tempest {
+    /// Returns a builder for configuring a UDP listener
+    pub fn builder() -> TempestBuilder {
+        TempestBuilder::new()
+    }
 
-            self.write_inner().stations_cached[index].air_temperature =
-                event.get_air_temperature().ok();
+    async fn bind(
+        ip: Option<IpAddr>,
+        port: Option<u16>,
+        multicast: Option<(Ipv4Addr, Ipv4Addr)>,
+    ) -> io::Result<Self> {
+        let ip = ip.unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        let port = port.unwrap_or(DEFAULT_PORT);
 
-            self.write_inner().stations_cached[index].relative_humidity =
-                event.get_relative_humidity().ok();
+        // the socket domain follows the address family
+        let sock = UdpSocket::bind(SocketAddr::new(ip, port)).await?;
 
-            self.write_inner().stations_cached[index].lightning_strike_count =
-                event.get_lightning_count().ok();
+        if let Some((group, interface)) = multicast {
+            trace!("Joining multicast group {group} on {interface}");
+            sock.join_multicast_v4(group, interface)?;
+        }
+        let arc_socket = Arc::new(sock);
 
-            self.write_inner().stations_cached[index].lightning_strike_avg_distance =
-                event.get_lightning_avg_distance().ok();
+        Ok(Self {
+            recv: arc_socket,
+            inner: Arc::new(RwLock::new(Inner::new())),
+            handle: None,
+            events: broadcast::channel(DEFAULT_BROADCAST_CAPACITY).0,
+            cache_updates: broadcast::channel(DEFAULT_BROADCAST_CAPACITY).0,
+            precip_transitions: broadcast::channel(DEFAULT_BROADCAST_CAPACITY).0,
+        })
+    }
 
-            // cache event
-            self.write_inner().stations_cached[index]
-                .air_event
-                .replace(event);
-        } else {
-            self.write_inner().stations_cached.push(event.into());
+    /// Detach from the listener task so it keeps running once this `Tempest` is dropped
+    fn detach(&mut self) {
+        if let Some(mut handle) = self.handle.take().and_then(Arc::into_inner) {
+            handle.detach();
         }
     }
 
-    /// Cache a ObservationSkyEvent into the station cache
-    fn cache_station_sky_event(&mut self, event: ObservationSkyEvent) {
-        let index = self.get_station_index(&event.get_serial_number());
+    /// Only share events that match the provided serial numbers from this point onwards, compared in their normalized
+    /// form and replacing any existing filter
+    ///
+    /// Events are matched by device or hub serial number as configured with `TempestBuilder::filter_by`. Caching is
+    /// unaffected by the filter.
+    pub fn set_filter(&self, serials: Vec<String>) {
+        self.write_inner().station_filter = Some(
+            serials
+                .iter()
+                .map(|serial| normalize_serial_number(serial))
+                .collect(),
+        );
+    }
 
-        if let Some(index) = index {
-            // general station info
-            self.write_inner().stations_cached[index].serial_number = event.get_serial_number();
+    /// Share every event from this point onwards, removing any filter
+    pub fn clear_filter(&self) {
+        self.write_inner().station_filter = None;
+    }
 
-            self.write_inner().stations_cached[index].hub_sn = event.get_hub_sn();
+    /// Register a handler invoked with every event shared by the listener from this point onwards
+    ///
+    /// Handlers are called in the order they were registered, on the listener task before the event is sent on the
+    /// channel and to broadcast subscribers, so they should return quickly. Events removed by the station filter or
+    /// dropped for failing validation are not passed to handlers.
+    pub fn on_event(&self, handler: impl Fn(&EventType) + Send + Sync + 'static) {
+        self.write_inner().event_handlers.push(Arc::new(handler));
+    }
 
-            self.write_inner().stations_cached[index].firmware_revision =
-                Some(event.get_firmware_revision());
+    /// Subscribe to every event shared by the listener from this point onwards
+    ///
+    /// Each subscriber receives its own copy of every event. A subscriber that falls more than 64 events behind
+    /// skips the oldest events it has missed and is told how many with `RecvError::Lagged` on its next receive.
+    ///
+    /// Subscribers are fed by the same listener task as the channel receiver. Under the default
+    /// `OverflowPolicy::Block`, a channel receiver that stops draining its queue pauses the listener once the queue is
+    /// full, and subscribers receive nothing further until it catches up. If subscribers are the main consumers,
+    /// drop or drain the channel receiver, or choose `OverflowPolicy::DropOldest` with `TempestBuilder::overflow_policy`.
+    pub fn subscribe(&self) -> broadcast::Receiver<EventType> {
+        self.events.subscribe()
+    }
 
-            self.write_inner().stations_cached[index].battery_voltage =
-                event.get_battery_voltage().unwrap_or_default();
+    /// Subscribe to the serial numbers of stations as their cached state is updated
+    ///
+    /// Updates arriving within 250ms of the first pending update are coalesced, so a burst of events from a
+    /// station is notified once. Notifications are only sent while caching is enabled.
+    /// Must be called from within a Tokio runtime.
+    pub fn cache_updates(&self) -> Receiver<String> {
+        let mut updates = self.cache_updates.subscribe();
+        let (tx, rx) = mpsc::channel(DEFAULT_BROADCAST_CAPACITY);
 
-            // common weather data
-            self.write_inner().stations_cached[index].illuminance =
-                event.get_illuminance().unwrap_or_default();
+        tokio::spawn(async move {
+            // serial numbers awaiting notification in the order they were first updated
+            let mut pending: Vec<String> = Vec::new();
+            let mut deadline: Option<Instant> = None;
 
-            self.write_inner().stations_cached[index].uv = event.get_uv().unwrap_or_default();
+            loop {
+                tokio::select! {
+                    update = updates.recv() => match update {
+                        Ok(serial_number) => {
+                            if !pending.contains(&serial_number) {
+                                pending.push(serial_number);
+                            }
 
-            self.write_inner().stations_cached[index].rain_amount_prev_minute =
-                event.get_rain_prev_min().unwrap_or_default();
+                            deadline.get_or_insert_with(|| Instant::now() + CACHE_UPDATE_DEBOUNCE);
+                        }
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            debug!("Skipped {skipped} cache updates");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = time::sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                        deadline = None;
+
+                        for serial_number in pending.drain(..) {
+                            if tx.send(serial_number).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    _ = tx.closed() => return,
+                }
+            }
 
-            self.write_inner().stations_cached[index].wind_lull =
-                event.get_wind_lull().unwrap_or_default();
+            for serial_number in pending {
+                let _ = tx.send(serial_number).await;
+            }
+        });
 
-            self.write_inner().stations_cached[index].wind_avg =
-                event.get_wind_avg().unwrap_or_default();
+        rx
+    }
 
-            self.write_inner().stations_cached[index].wind_gust =
-                event.get_wind_gust().unwrap_or_default();
+    /// Sample the latest cached value of a metric of a station every `interval` based on the provided station's serial number
+    ///
+    /// The most recent value is held and emitted again until a new reading is cached (zero-order hold), giving a
+    /// series at a fixed cadence regardless of when observations arrive. Nothing is emitted until the station has
+    /// reported the metric. Sampling stops once the receiver is dropped and does not keep the listener running.
+    /// Must be called from within a Tokio runtime.
+    pub fn resampled_stream(
+        &self,
+        serial_number: &str,
+        metric: SampledMetric,
+        interval: Duration,
+    ) -> Receiver<f32> {
+        let tempest = Tempest {
+            handle: None,
+            ..self.clone()
+        };
+        let serial_number = serial_number.to_string();
+        let (tx, rx) = mpsc::channel(DEFAULT_BROADCAST_CAPACITY);
 
-            self.write_inner().stations_cached[index].wind_direction =
-                event.get_wind_direction().unwrap_or_default();
+        tokio::spawn(async move {
+            let mut ticks = time::interval(interval);
+            let mut held: Option<f32> = None;
 
-            self.write_inner().stations_cached[index].solar_radiation =
-                event.get_solar_radiation().unwrap_or_default();
+            loop {
+                tokio::select! {
+                    _ = ticks.tick() => {
+                        let latest = tempest
+                            .get_station_by_sn(&serial_number)
+                            .and_then(|station| station.latest(metric));
+
+                        held = latest.or(held);
+
+                        if let Some(value) = held
+                            && tx.send(value).await.is_err()
+                        {
+                            return;
+                        }
+                    }
+                    _ = tx.closed() => return,
+                }
+            }
+        });
 
-            self.write_inner().stations_cached[index].precipitation_type =
-                event.get_precip_type().ok();
+        rx
+    }
 
-            // cache event
-            self.write_inner().stations_cached[index]
-                .sky_event
-                .replace(event);
-        } else {
-            self.write_inner().stations_cached.push(event.into());
-        }
+    /// Subscribe to the onset and cessation of precipitation at every station from this point onwards
+    ///
+    /// Precipitation starts as soon as a rain start event, or an observation reporting a precipitation type other
+    /// than none, arrives. It stops once an observation reporting no precipitation arrives at least 60 seconds after
+    /// the last precipitation, so brief gaps are not reported as transitions. Transitions are detected whether or
+    /// not caching is enabled.
+    pub fn precip_transitions(&self) -> broadcast::Receiver<PrecipTransition> {
+        self.precip_transitions.subscribe()
     }
 
-    /// Cache a DeviceStatusEvent into the station cache
-    fn cache_station_device_status(&mut self, event: DeviceStatusEvent) {
-        let index = self.get_station_index(&event.get_serial_number());
+    /// Track the precipitation reported by an event, sharing any onset or cessation with subscribers
+    fn detect_precip_transition(&self, event: &EventType) {
+        let precipitating = match event {
+            EventType::Rain(_) => true,
+            EventType::Observation(event) => match event.get_precip_type() {
+                Ok(precipitation_type) => precipitation_type.is_precipitating(),
+                Err(_) => return,
+            },
+            EventType::Sky(event) => match event.get_precip_type() {
+                Ok(precipitation_type) => precipitation_type.is_precipitating(),
+                Err(_) => return,
+            },
+            _ => return,
+        };
+
+        let serial_number = event.get_serial_number();
+        let now = self.now();
+        let mut inner = self.write_inner();
+
+        let transition = if precipitating {
+            inner
+                .precipitating
+                .insert(serial_number.clone(), now)
+                .is_none()
+                .then_some(PrecipTransition::Started { serial_number })
+        } else {
+            let stopped = inner.precipitating.get(&serial_number).is_some_and(|last| {
+                now.duration_since(*last).unwrap_or_default() >= PRECIP_STOPPED_DEBOUNCE
+            });
 
-        if let Some(index) = index {
-            // general station info
-            self.write_inner().stations_cached[index].serial_number = event.get_serial_number();
+            stopped.then(|| {
+                inner.precipitating.remove(&serial_number);
+                PrecipTransition::Stopped { serial_number }
+            })
+        };
 
-            self.write_inner().stations_cached[index].hub_sn = event.get_hub_sn();
+        if let Some(transition) = transition {
+            debug!("{transition}");
 
-            self.write_inner().stations_cached[index].firmware_revision =
-                Some(event.get_firmware_revision());
+            // having no subscribers is not an error
+            let _ = self.precip_transitions.send(transition);
+        }
+    }
 
-            self.write_inner().stations_cached[index].battery_voltage =
-                Some(event.get_battery_voltage());
+    /// Share an event with broadcast subscribers and the channel receiver, if it is still open
+    async fn share_event(&self, sink: &EventSink, event: EventType) {
+        // having no subscribers is not an error
+        let _ = self.events.send(event.clone());
 
-            // cache event
-            self.write_inner().stations_cached[index]
-                .device_status
-                .replace(event);
-        } else {
-            self.write_inner().stations_cached.push(event.into());
+        if sink.send(event).await {
+            self.write_inner().dropped_events += 1;
         }
     }
 
-    /// Retrieve a hub from the cache based on the provided serial number
+    /// Returns a count of the number of events dropped from the channel receiver by the overflow policy
+    pub fn dropped_event_count(&self) -> u64 {
+        self.read_inner().dropped_events
+    }
+
+    /// Signal the listener task to stop and wait for it to finish
     ///
-    /// Returns Some(Hub) if the hub is present in the cache, otherwise None
-    pub fn get_hub_by_sn(&self, serial_number: &str) -> Option<Hub> {
-        for hub in self.read_inner().hubs_cached.iter() {
-            if hub.serial_number == serial_number {
-                return Some(hub.clone());
-            }
+    /// The listener stops even while waiting for a full channel receiver to make room, after which the receiver is
+    /// closed. The socket is shared by every clone of this `Tempest` instance, so the port stays bound until every
+    /// clone is dropped. Does nothing if the listener has already been shut down.
+    pub async fn shutdown(&self) {
+        let Some(handle) = &self.handle else {
+            return;
+        };
+
+        if let Some(shutdown) = &handle.shutdown {
+            trace!("Signalling listener task to stop");
+            shutdown.notify_one();
         }
 
-        None
+        let task = handle
+            .task
+            .lock()
+            .expect("Unable to acquire listener task lock")
+            .take();
+
+        if let Some(task) = task {
+            let _ = task
+                .await
+                .inspect_err(|e| error!("Listener task failed: {e}"));
+        }
     }
 
-    /// Retrieve a hub from the cache associated with the provided station
-    ///
-    /// If the hub is in the cache then Some(Hub) is returned, otherwise None if not present
-    pub fn get_hub_from_station(&self, station: Station) -> Option<Hub> {
-        self.get_hub_by_sn(&station.hub_sn)
+    /// Returns the current time from the configured clock
+    fn now(&self) -> SystemTime {
+        self.read_inner().clock.now()
     }
 
-    /// Get the vector index of a cached hub based on the provided hub serial number
-    ///
-    /// If station is in the cache then Some(index) is returned, otherwise None if not present.
-    fn get_hub_index(&self, serial_number: &str) -> Option<usize> {
-        for (index, hub) in self.read_inner().hubs_cached.iter().enumerate() {
-            if hub.serial_number == serial_number {
-                return Some(index);
-            }
-        }
+    /// Grabs the shared read lock of the inner
+    fn read_inner(&self) -> RwLockReadGuard<'_, Inner> {
+        self.inner.read().expect("Unable to acquire read lock")
+    }
 
-        None
+    /// Grabs the exclusive write lock of the inner
+    fn write_inner(&self) -> RwLockWriteGuard<'_, Inner> {
+        self.inner.write().expect("Unable to acquire write lock")
     }
 
-    /// Get the vector index of a cached station based on the provided hub serial number
+    /// Record the outcome of validating a received event
     ///
-    /// If station is in the cache then Some(index) is returned, otherwise None is not present.
-    fn get_station_index(&self, serial_number: &str) -> Option<usize> {
-        for (index, station) in self.read_inner().stations_cached.iter().enumerate() {
-            if station.serial_number == serial_number {
-                return Some(index);
+    /// Returns true if the event is valid, otherwise counts the rejection and returns false
+    fn validated(&self, result: Result<(), EventError>) -> bool {
+        match result {
+            Ok(()) => true,
+            Err(e) => {
+                debug!("Skipping caching of invalid event: {e:?}");
+                self.write_inner().rejected_events += 1;
+                false
             }
         }
-
-        None
     }
 
-    /// Retrieve a station from the cache based on the provided serial number
-    pub fn get_station_by_sn(&self, serial_number: &str) -> Option<Station> {
-        for station in self.read_inner().stations_cached.iter() {
-            if station.serial_number == serial_number {
-                return Some(station.clone());
-            }
-        }
-
-        None
+    /// Returns a count of the number of events that failed validation and were not cached
+    pub fn rejected_event_count(&self) -> u64 {
+        self.read_inner().rejected_events
     }
 
-    /// Retrieve a vector of stations from the cache based on the associated hub's serial number
-    pub fn get_stations_by_hub_sn(&self, serial_number: &str) -> Vec<Station> {
-        let mut stations: Vec<Station> = Vec::new();
+    /// Returns a count of the number of packets that filled the receive buffer and may have been truncated
+    pub fn truncated_packet_count(&self) -> u64 {
+        self.read_inner().truncated_packets
+    }
 
-        for station in self.read_inner().stations_cached.iter() {
-            if station.hub_sn == serial_number {
-                stations.push(station.clone());
-            }
+    /// Returns a snapshot of the listener's counters taken under a single lock
+    pub fn metrics(&self) -> Metrics {
+        let inner = self.read_inner();
+
+        Metrics {
+            packets_received: inner.packets_received,
+            parse_errors: inner.parse_errors,
+            unknown_events: inner.unknown_events,
+            truncated_packets: inner.truncated_packets,
+            rejected_events: inner.rejected_events,
+            dropped_events: inner.dropped_events,
+            events: inner.event_counts.clone(),
         }
+    }
 
-        stations
+    /// Returns a count of the number of stations cached
+    pub fn station_count(&self) -> usize {
+        self.read_inner().stations_cached.len()
     }
 
-    /// Retrieve the most recent battery voltage of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_battery_voltage(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.battery_voltage)?
+    /// Returns a count of the number of hubs cached
+    pub fn hub_count(&self) -> usize {
+        self.read_inner().hubs_cached.len()
     }
 
-    /// Retrieve the most recent wind speed lull of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_wind_lull(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.wind_lull)?
+    /// Remove every cached hub and station, which are cached afresh as new events arrive
+    pub fn clear_cache(&self) {
+        let mut inner = self.write_inner();
+        inner.stations_cached.clear();
+        inner.hubs_cached.clear();
     }
 
-    /// Retrieve the most recent wind speed average of a cached station based on the provided station's serial number
+    /// Remove a cached station based on the provided station's serial number, such as once it is decommissioned
     ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_wind_avg(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.wind_avg)?
+    /// Returns true if the station was cached
+    pub fn remove_station(&self, serial_number: &str) -> bool {
+        self.write_inner()
+            .stations_cached
+            .remove(&normalize_serial_number(serial_number))
+            .is_some()
     }
 
-    /// Retrieve the most recent wind speed gust of a cached station based on the provided station's serial number
+    /// Remove a cached hub based on the provided hub's serial number, leaving the stations reporting through it cached
     ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_wind_gust(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.wind_gust)?
+    /// Returns true if the hub was cached
+    pub fn remove_hub(&self, serial_number: &str) -> bool {
+        self.write_inner()
+            .hubs_cached
+            .remove(&normalize_serial_number(serial_number))
+            .is_some()
     }
 
-    /// Retrieve the most recent wind direction of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_wind_direction(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.wind_direction)?
+    /// Returns a snapshot of every cached station taken under a single lock
+    pub fn all_stations(&self) -> Vec<Station> {
+        self.read_inner()
+            .stations_cached
+            .values()
+            .cloned()
+            .collect()
     }
 
-    /// Retrieve the most recent wind speed of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_wind_speed(&self, serial_number: &str) -> Option<f32> {
-        Some(
-            self.get_station_by_sn(serial_number)?
-                .wind_event?
-                .get_wind_speed_mps(),
-        )
+    /// Returns a snapshot of every cached hub taken under a single lock
+    pub fn all_hubs(&self) -> Vec<Hub> {
+        self.read_inner().hubs_cached.values().cloned().collect()
     }
 
-    /// Retrieve the most recent station pressure (MB, millibars) of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_station_pressure(&self, serial_number: &str) -> Option<f32> {
+    /// Returns the serial numbers of every cached station in sorted order
+    pub fn station_serials(&self) -> Vec<String> {
+        let mut serials: Vec<String> = self.read_inner().stations_cached.keys().cloned().collect();
+
+        serials.sort();
+        serials
+    }
+
+    /// Returns the serial numbers of every cached hub in sorted order
+    pub fn hub_serials(&self) -> Vec<String> {
+        let mut serials: Vec<String> = self.read_inner().hubs_cached.keys().cloned().collect();
+
+        serials.sort();
+        serials
+    }
+
+    /// Insert or replace the provided hub into the hub cache, carrying over the radio reboot count and first seen
+    /// time of the replaced hub
+    fn hub_upsert(&mut self, mut hub_data: Hub) {
+        let received = self.now();
+        hub_data.first_seen = Some(received);
+        hub_data.last_seen = Some(received);
+
+        let mut inner = self.write_inner();
+
+        if let Some(previous) = inner.hubs_cached.get(&hub_data.serial_number) {
+            trace!("Replacing existing hub record");
+            hub_data.previous_reboot_count = Some(previous.radio_stats.reboot_count);
+            hub_data.first_seen = previous.first_seen.or(hub_data.first_seen);
+        }
+
+        inner
+            .hubs_cached
+            .insert(hub_data.serial_number.clone(), hub_data);
+    }
+
+    /// Record the local time an event from a cached station was received, along with the clock skew
+    /// against the event's timestamp when it reports one with whole second precision
+    fn record_arrival(&self, serial_number: &str, timestamp: Option<u64>) {
+        // having no subscribers is not an error
+        let _ = self.cache_updates.send(serial_number.to_string());
+
+        let received = self.now();
+        let mut inner = self.write_inner();
+
+        if let Some(station) = inner.stations_cached.get_mut(serial_number) {
+            station.last_seen = Some(received);
+            station.first_seen.get_or_insert(received);
+
+            if let Some(timestamp) = timestamp {
+                let local = received
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs();
+
+                station.clock_skew = Some(timestamp as i64 - local as i64);
+            }
+        }
+    }
+
+    /// Widen a cached station's apparent temperature extremes with its current readings
+    fn record_feels_like(&self, serial_number: &str) {
+        if let Some(station) = self.write_inner().stations_cached.get_mut(serial_number) {
+            station.record_feels_like();
+        }
+    }
+
+    /// Add the previous minute's rain (mm) of an observation to a cached station's accumulation
+    ///
+    /// Observations without a timestamp, or at or before the last one accumulated, are skipped so a
+    /// resent or late observation is not counted twice.
+    fn accumulate_rain(&self, serial_number: &str, timestamp: Option<u64>, rain: Option<f32>) {
+        let Some(timestamp) = timestamp else {
+            return;
+        };
+
+        if let Some(station) = self.write_inner().stations_cached.get_mut(serial_number) {
+            if station
+                .last_rain_timestamp
+                .is_some_and(|last| timestamp <= last)
+            {
+                trace!("Skipping rain already accumulated for {serial_number}");
+                return;
+            }
+
+            station.last_rain_timestamp = Some(timestamp);
+
+            if let Some(rain) = rain.filter(|rain| rain.is_finite() && *rain > 0.0) {
+                station.rain_accumulated += rain;
+            }
+        }
+    }
+
+    /// Cache an event into the hub or station cache based on its type
+    fn cache_event(&mut self, event: EventType) {
+        match event {
+            EventType::Rain(event) => self.cache_station_rain_event(event),
+            EventType::Lightning(event) => self.cache_station_lightning_event(event),
+            EventType::RapidWind(event) => self.cache_station_wind_event(event),
+            EventType::Observation(event) => self.cache_station_observation(event),
+            EventType::Air(event) => self.cache_station_air_event(event),
+            EventType::Sky(event) => self.cache_station_sky_event(event),
+            EventType::DeviceStatus(event) => self.cache_station_device_status(event),
+            EventType::HubStatus(event) => self.hub_upsert(Hub::from(event)),
+            EventType::Unknown { .. } => {}
+        }
+    }
+
+    /// Cache a ObservationEvent into the station cache
+    fn cache_station_observation(&mut self, observation: ObservationEvent) {
+        let serial_number = observation.get_serial_number();
+        let timestamp = observation.get_timestamp_u64().ok();
+        let received = self.now();
+        let rain = observation.get_rain_amount_prev_min().ok();
+        let smoothing = self.read_inner().air_temperature_smoothing;
+
+        if let Some(station) = self.write_inner().stations_cached.get_mut(&serial_number) {
+            // general station info
+            station.firmware_revision = Some(observation.get_firmware_revision());
+
+            station.hub_sn = observation.get_hub_sn();
+
+            station.serial_number = observation.get_serial_number();
+
+            station.battery_voltage = observation.get_battery_voltage().ok();
+
+            // common weather data
+            station.station_pressure = observation.get_station_pressure().ok();
+
+            station.air_temperature = observation.get_air_temperature().ok();
+
+            station.relative_humidity = observation.get_rh().ok();
+
+            station.lightning_strike_count = observation.get_lightning_strike_count().ok();
+
+            station.lightning_strike_avg_distance = observation.get_lightning_avg_distance().ok();
+
+            station.illuminance = observation.get_illuminance().ok();
+
+            station.uv = observation.get_uv().ok();
+
+            station.rain_amount_prev_minute = observation.get_rain_amount_prev_min().ok();
+
+            station.wind_lull = observation.get_wind_lull().ok();
+
+            station.wind_avg = observation.get_wind_avg().ok();
+
+            station.wind_gust = observation.get_wind_gust().ok();
+
+            station.wind_direction = observation.get_wind_direction().ok();
+
+            station.solar_radiation = observation.get_solar_radiation().ok();
+
+            station.precipitation_type = observation.get_precip_type().ok();
+
+            // recent readings
+            push_sample(
+                &mut station.air_temperature_samples,
+                observation.get_air_temperature().ok(),
+            );
+
+            push_sample(
+                &mut station.station_pressure_samples,
+                observation.get_station_pressure().ok(),
+            );
+
+            push_sample(
+                &mut station.relative_humidity_samples,
+                observation.get_rh().ok(),
+            );
+
+            station.air_temperature_smoothed = smooth(
+                station.air_temperature_smoothed,
+                observation.get_air_temperature().ok(),
+                smoothing,
+            );
+
+            record_temperature_minutes(
+                &mut station.temperature_minutes,
+                observation.get_air_temperature().ok(),
+                observation.get_report_interval().ok(),
+            );
+
+            // arrival timing
+            let previous = station.last_observation_received.replace(received);
+
+            station.observation_arrival_interval = previous
+                .and_then(|previous| received.duration_since(previous).ok())
+                .map(|interval| interval.as_secs_f32());
+
+            // cache event
+            station.observation.replace(observation);
+        } else {
+            let mut station: Station = observation.into();
+            station.last_observation_received = Some(received);
+
+            self.write_inner()
+                .stations_cached
+                .insert(serial_number.clone(), station);
+        }
+
+        self.accumulate_rain(&serial_number, timestamp, rain);
+
+        self.record_feels_like(&serial_number);
+        self.record_arrival(&serial_number, timestamp);
+    }
+
+    /// Cache a RapidWindEvent into the station cache
+    fn cache_station_wind_event(&mut self, event: RapidWindEvent) {
+        let serial_number = event.get_serial_number();
+        let timestamp = event.get_timestamp_checked().ok();
+        let (hold_calm_wind_direction, wind_history_capacity) = {
+            let inner = self.read_inner();
+            (inner.hold_calm_wind_direction, inner.wind_history_capacity)
+        };
+
+        // the cached direction otherwise comes only from observations, so rapid wind updates it
+        // just when holding it through a calm
+        let calm = event.get_wind_speed_mps() == 0.0 && event.get_wind_direction() == 0;
+        let direction =
+            (hold_calm_wind_direction && !calm).then(|| event.get_wind_direction() as f32);
+
+        if hold_calm_wind_direction && calm {
+            trace!("Holding cached wind direction during calm");
+        }
+
+        if let Some(station) = self.write_inner().stations_cached.get_mut(&serial_number) {
+            if direction.is_some() {
+                station.wind_direction = direction;
+            }
+
+            let identical = station.wind_event.as_ref().is_some_and(|previous| {
+                previous.get_wind_speed_mps() == event.get_wind_speed_mps()
+                    && previous.get_wind_direction() == event.get_wind_direction()
+            });
+
+            station.identical_wind_readings = if identical {
+                station.identical_wind_readings.saturating_add(1)
+            } else {
+                1
+            };
+
+            if let Some(timestamp) = timestamp {
+                push_wind(
+                    &mut station.wind_history,
+                    wind_history_capacity,
+                    timestamp,
+                    event.get_wind_speed_mps(),
+                );
+            }
+
+            station.wind_event.replace(event);
+        } else {
+            let mut station: Station = event.into();
+            station.wind_direction = direction;
+            self.write_inner()
+                .stations_cached
+                .insert(serial_number.clone(), station);
+        }
+
+        self.record_arrival(&serial_number, timestamp);
+    }
+
+    /// Cache a RainStartEvent into the station cache
+    fn cache_station_rain_event(&mut self, event: RainStartEvent) {
+        let serial_number = event.get_serial_number();
+        let timestamp = event.try_get_timestamp().ok();
+
+        if let Some(station) = self.write_inner().stations_cached.get_mut(&serial_number) {
+            station.rain_event.replace(event);
+        } else {
+            self.write_inner()
+                .stations_cached
+                .insert(serial_number.clone(), event.into());
+        }
+
+        self.record_arrival(&serial_number, timestamp);
+    }
+
+    /// Cache a LightningStrikeEvent into the station cache
+    fn cache_station_lightning_event(&mut self, event: LightningStrikeEvent) {
+        let serial_number = event.get_serial_number();
+        let timestamp = event.try_get_timestamp().ok();
+
+        if let Some(station) = self.write_inner().stations_cached.get_mut(&serial_number) {
+            push_strike(&mut station.lightning_history, event.clone());
+            station.lightning_event.replace(event);
+        } else {
+            self.write_inner()
+                .stations_cached
+                .insert(serial_number.clone(), event.into());
+        }
+
+        self.record_arrival(&serial_number, timestamp);
+    }
+
+    /// Cache a ObservationAirEvent into the station cache
+    fn cache_station_air_event(&mut self, event: ObservationAirEvent) {
+        let serial_number = event.get_serial_number();
+        let timestamp = event.get_timestamp_u64().ok();
+        let smoothing = self.read_inner().air_temperature_smoothing;
+
+        if let Some(station) = self.write_inner().stations_cached.get_mut(&serial_number) {
+            // general station info
+            station.serial_number = event.get_serial_number();
+
+            station.hub_sn = event.get_hub_sn();
+
+            station.firmware_revision = Some(event.get_firmware_revision());
+
+            station.battery_voltage = event.get_battery_voltage().ok();
+
+            // common weather data
+            station.station_pressure = event.get_station_pressure().ok();
+
+            station.air_temperature = event.get_air_temperature().ok();
+
+            station.relative_humidity = event.get_relative_humidity().ok();
+
+            station.lightning_strike_count = event.get_lightning_count().ok();
+
+            station.lightning_strike_avg_distance = event.get_lightning_avg_distance().ok();
+
+            // recent readings
+            push_sample(
+                &mut station.air_temperature_samples,
+                event.get_air_temperature().ok(),
+            );
+
+            push_sample(
+                &mut station.station_pressure_samples,
+                event.get_station_pressure().ok(),
+            );
+
+            push_sample(
+                &mut station.relative_humidity_samples,
+                event.get_relative_humidity().ok(),
+            );
+
+            station.air_temperature_smoothed = smooth(
+                station.air_temperature_smoothed,
+                event.get_air_temperature().ok(),
+                smoothing,
+            );
+
+            record_temperature_minutes(
+                &mut station.temperature_minutes,
+                event.get_air_temperature().ok(),
+                event.get_report_interval().ok(),
+            );
+
+            // cache event
+            station.air_event.replace(event);
+        } else {
+            self.write_inner()
+                .stations_cached
+                .insert(serial_number.clone(), event.into());
+        }
+
+        self.record_feels_like(&serial_number);
+        self.record_arrival(&serial_number, timestamp);
+    }
+
+    /// Cache a ObservationSkyEvent into the station cache
+    fn cache_station_sky_event(&mut self, event: ObservationSkyEvent) {
+        let serial_number = event.get_serial_number();
+        let timestamp = event.get_timestamp_u64().ok();
+        let rain = event.get_rain_prev_min().ok().flatten();
+
+        if let Some(station) = self.write_inner().stations_cached.get_mut(&serial_number) {
+            // general station info
+            station.serial_number = event.get_serial_number();
+
+            station.hub_sn = event.get_hub_sn();
+
+            station.firmware_revision = Some(event.get_firmware_revision());
+
+            station.battery_voltage = event.get_battery_voltage().unwrap_or_default();
+
+            // common weather data
+            station.illuminance = event.get_illuminance().unwrap_or_default();
+
+            station.uv = event.get_uv().unwrap_or_default();
+
+            station.rain_amount_prev_minute = event.get_rain_prev_min().unwrap_or_default();
+
+            station.wind_lull = event.get_wind_lull().unwrap_or_default();
+
+            station.wind_avg = event.get_wind_avg().unwrap_or_default();
+
+            station.wind_gust = event.get_wind_gust().unwrap_or_default();
+
+            station.wind_direction = event.get_wind_direction().unwrap_or_default();
+
+            station.solar_radiation = event.get_solar_radiation().unwrap_or_default();
+
+            station.precipitation_type = event.get_precip_type().ok();
+
+            station.local_day_rain_accum = event.get_local_day_rain_accum().unwrap_or_default();
+
+            // cache event
+            station.sky_event.replace(event);
+        } else {
+            self.write_inner()
+                .stations_cached
+                .insert(serial_number.clone(), event.into());
+        }
+
+        self.accumulate_rain(&serial_number, timestamp, rain);
+
+        self.record_feels_like(&serial_number);
+        self.record_arrival(&serial_number, timestamp);
+    }
+
+    /// Cache a DeviceStatusEvent into the station cache
+    fn cache_station_device_status(&mut self, event: DeviceStatusEvent) {
+        let serial_number = event.get_serial_number();
+        let timestamp = Some(event.get_timestamp());
+
+        if let Some(station) = self.write_inner().stations_cached.get_mut(&serial_number) {
+            // general station info
+            station.serial_number = event.get_serial_number();
+
+            station.hub_sn = event.get_hub_sn();
+
+            station.firmware_revision = Some(event.get_firmware_revision());
+
+            station.battery_voltage = Some(event.get_battery_voltage());
+
+            // cache event
+            station.device_status.replace(event);
+        } else {
+            self.write_inner()
+                .stations_cached
+                .insert(serial_number.clone(), event.into());
+        }
+
+        self.record_arrival(&serial_number, timestamp);
+    }
+
+    /// Retrieve a hub from the cache based on the provided serial number
+    ///
+    /// Returns Some(Hub) if the hub is present in the cache, otherwise None
+    pub fn get_hub_by_sn(&self, serial_number: &str) -> Option<Hub> {
+        self.read_inner()
+            .hubs_cached
+            .get(&normalize_serial_number(serial_number))
+            .cloned()
+    }
+
+    /// Retrieve the serial numbers of cached hubs whose most recent status is older than the provided window
+    ///
+    /// Staleness is based on the timestamp reported by the hub compared against the current time of the configured clock.
+    pub fn stale_hubs(&self, within: Duration) -> Vec<String> {
+        let now = self.now().duration_since(UNIX_EPOCH).unwrap_or_default();
+
+        self.read_inner()
+            .hubs_cached
+            .values()
+            .filter(|hub| now.saturating_sub(Duration::from_secs(hub.timestamp)) > within)
+            .map(|hub| hub.serial_number.clone())
+            .collect()
+    }
+
+    /// Check whether the wind sensor of a cached station appears stuck based on the provided station's serial number
+    ///
+    /// The sensor is considered stuck once at least `consecutive` rapid wind events in a row have reported the exact
+    /// same speed and direction. Calm readings legitimately repeat and are never considered stuck.
+    ///
+    /// Returns the value as a Some(..) if the station has reported rapid wind otherwise returns a None
+    pub fn wind_sensor_stuck(&self, serial_number: &str, consecutive: usize) -> Option<bool> {
+        let station = self.get_station_by_sn(serial_number)?;
+        let event = station.wind_event?;
+
+        if event.get_wind_speed_mps() == 0.0 {
+            return Some(false);
+        }
+
+        Some(station.identical_wind_readings as usize >= consecutive)
+    }
+
+    /// Retrieve the recent rapid wind samples of a cached station as (timestamp, speed in m/s) pairs, oldest first
+    ///
+    /// The number of samples retained is set by `TempestBuilder::wind_history_capacity`.
+    ///
+    /// Returns an empty vector if the station is not cached or has not reported rapid wind
+    pub fn wind_history(&self, serial_number: &str) -> Vec<(u64, f32)> {
         self.get_station_by_sn(serial_number)
-            .map(|station| station.station_pressure)?
+            .map(|station| station.wind_history.into())
+            .unwrap_or_default()
+    }
+
+    /// Maximum rapid wind speeds (m/s, meters per second) of a station over the last one and ten minutes
+    ///
+    /// The windows end at the most recent rapid wind sample, and only cover the samples retained in the station's
+    /// wind history.
+    ///
+    /// Returns the values as a Some(..) if the station has reported rapid wind otherwise returns a None
+    pub fn gust_windows(&self, serial_number: &str) -> Option<GustWindows> {
+        GustWindows::from_history(&self.get_station_by_sn(serial_number)?.wind_history)
+    }
+
+    /// Coldest and hottest apparent temperature (C, celsius) seen by a station, see `Station::feels_like`
+    ///
+    /// The extremes are tracked as observations are cached, so they cover every reading since the station was
+    /// first cached rather than only the recent samples.
+    ///
+    /// Returns the values as a Some((coldest, hottest)) if the station has reported enough to compute an apparent
+    /// temperature otherwise returns a None
+    pub fn feels_like_extremes(&self, serial_number: &str) -> Option<(f32, f32)> {
+        self.get_station_by_sn(serial_number)?.feels_like_extremes
+    }
+
+    /// Remove cached stations that have not been heard from within the provided window, returning the number removed
+    ///
+    /// Staleness is based on the local time the station's last event was received according to the configured clock,
+    /// so a window of zero removes every station not heard from at the current instant. Call this periodically,
+    /// such as from a `tokio::time::interval`, to stop stations that no longer report from accumulating.
+    pub fn evict_stale(&self, max_age: Duration) -> usize {
+        let now = self.now();
+        let mut inner = self.write_inner();
+        let cached = inner.stations_cached.len();
+
+        inner
+            .stations_cached
+            .retain(|_, station| match station.last_seen {
+                Some(last_seen) => now.duration_since(last_seen).unwrap_or_default() <= max_age,
+                None => true,
+            });
+
+        let evicted = cached - inner.stations_cached.len();
+
+        if evicted > 0 {
+            debug!("Evicted {evicted} stale stations");
+        }
+
+        evicted
+    }
+
+    /// Retrieve the raw JSON of the last packet received from a device based on the provided serial number
+    ///
+    /// Only available when enabled with `TempestBuilder::store_raw_json`.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn last_raw_json(&self, serial_number: &str) -> Option<String> {
+        self.read_inner()
+            .raw_json
+            .get(&normalize_serial_number(serial_number))
+            .cloned()
+    }
+
+    /// Whether a cached hub's radio is healthy, being on and not having rebooted since the hub's previous status
+    ///
+    /// Returns the value as a Some(..) if the hub is cached otherwise returns a None
+    pub fn hub_radio_healthy(&self, serial_number: &str) -> Option<bool> {
+        self.get_hub_by_sn(serial_number)
+            .map(|hub| hub.radio_healthy())
+    }
+
+    /// Retrieve the radio network ID of every cached hub keyed by the hub's serial number
+    pub fn radio_network_ids(&self) -> HashMap<String, u16> {
+        self.read_inner()
+            .hubs_cached
+            .iter()
+            .map(|(serial_number, hub)| (serial_number.clone(), hub.radio_stats.radio_network_id))
+            .collect()
+    }
+
+    /// Retrieve the radio network IDs shared by more than one cached hub, in ascending order
+    ///
+    /// Nearby hubs sharing a radio network ID may interfere with each other.
+    pub fn duplicate_radio_networks(&self) -> Vec<u16> {
+        let mut counts: HashMap<u16, usize> = HashMap::new();
+
+        for network_id in self.radio_network_ids().into_values() {
+            *counts.entry(network_id).or_default() += 1;
+        }
+
+        let mut duplicates: Vec<u16> = counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(network_id, _)| network_id)
+            .collect();
+
+        duplicates.sort_unstable();
+        duplicates
+    }
+
+    /// Retrieve a hub from the cache associated with the provided station
+    ///
+    /// If the hub is in the cache then Some(Hub) is returned, otherwise None if not present
+    pub fn get_hub_from_station(&self, station: Station) -> Option<Hub> {
+        self.get_hub_by_sn(&station.hub_sn)
+    }
+
+    /// Retrieve a station from the cache based on the provided serial number
+    pub fn get_station_by_sn(&self, serial_number: &str) -> Option<Station> {
+        self.read_inner()
+            .stations_cached
+            .get(&normalize_serial_number(serial_number))
+            .cloned()
+    }
+
+    /// Retrieve a vector of stations from the cache based on the associated hub's serial number
+    pub fn get_stations_by_hub_sn(&self, serial_number: &str) -> Vec<Station> {
+        let serial_number = normalize_serial_number(serial_number);
+        let mut stations: Vec<Station> = Vec::new();
+
+        for station in self.read_inner().stations_cached.values() {
+            if station.hub_sn == serial_number {
+                stations.push(station.clone());
+            }
+        }
+
+        stations
+    }
+
+    /// Summarise the most recent temperature, wind and pressure readings of every cached station reporting through the provided hub
+    ///
+    /// Returns the aggregate as a Some(..) if any cached station reports through the hub otherwise returns a None
+    pub fn hub_aggregate(&self, hub_serial: &str) -> Option<HubAggregate> {
+        let stations = self.get_stations_by_hub_sn(hub_serial);
+
+        if stations.is_empty() {
+            return None;
+        }
+
+        Some(HubAggregate::from_stations(hub_serial, &stations))
+    }
+
+    /// Retrieve the mean air temperature (C, celsius) across the cached stations reporting through the provided hub
+    ///
+    /// Returns the value as a Some(..) if any of the stations has a reading otherwise returns a None
+    pub fn hub_average_temperature(&self, hub_serial: &str) -> Option<f32> {
+        self.hub_average(hub_serial, SampledMetric::AirTemperature)
+    }
+
+    /// Retrieve the mean station pressure (MB, millibars) across the cached stations reporting through the provided hub
+    ///
+    /// Returns the value as a Some(..) if any of the stations has a reading otherwise returns a None
+    pub fn hub_average_pressure(&self, hub_serial: &str) -> Option<f32> {
+        self.hub_average(hub_serial, SampledMetric::StationPressure)
+    }
+
+    /// Retrieve the mean relative humidity (%) across the cached stations reporting through the provided hub
+    ///
+    /// Returns the value as a Some(..) if any of the stations has a reading otherwise returns a None
+    pub fn hub_average_humidity(&self, hub_serial: &str) -> Option<f32> {
+        self.hub_average(hub_serial, SampledMetric::RelativeHumidity)
     }
 
-    /// Retrieve the most recent air temperature (C, celsius) of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_air_temperature(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.air_temperature)?
-    }
+    /// Mean of the most recent readings of a metric across the stations reporting through a hub, skipping stations without one
+    fn hub_average(&self, hub_serial: &str, metric: SampledMetric) -> Option<f32> {
+        MetricSummary::from_values(
+            self.get_stations_by_hub_sn(hub_serial)
+                .iter()
+                .filter_map(|station| station.latest(metric)),
+        )
+        .map(|summary| summary.average)
+    }
+
+    /// Collect the serial number and RSSI of every cached station with a device status
+    fn station_signals(&self) -> Vec<(String, i16)> {
+        self.read_inner()
+            .stations_cached
+            .values()
+            .filter_map(|station| {
+                station
+                    .device_status
+                    .as_ref()
+                    .map(|status| (station.serial_number.clone(), status.get_rssi()))
+            })
+            .collect()
+    }
+
+    /// Retrieve the cached station with the weakest signal strength (RSSI) from its most recent device status
+    ///
+    /// Returns the station's serial number and RSSI as a Some(..) if present otherwise returns a None
+    pub fn weakest_signal_station(&self) -> Option<(String, i16)> {
+        self.station_signals()
+            .into_iter()
+            .min_by_key(|(_, rssi)| *rssi)
+    }
+
+    /// Retrieve the cached station with the strongest signal strength (RSSI) from its most recent device status
+    ///
+    /// Returns the station's serial number and RSSI as a Some(..) if present otherwise returns a None
+    pub fn strongest_signal_station(&self) -> Option<(String, i16)> {
+        self.station_signals()
+            .into_iter()
+            .max_by_key(|(_, rssi)| *rssi)
+    }
+
+    /// Retrieve the most recent battery voltage of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_battery_voltage(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.battery_voltage)?
+    }
+
+    /// Retrieve the most recent wind speed lull of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_wind_lull(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.wind_lull)?
+    }
+
+    /// Retrieve the most recent wind speed average of a cached station based on the provided station's serial number
+    ///
+    /// The value is read from whichever of the station's observation, sky observation or rapid wind samples is
+    /// newest, see `Station::freshest_wind_avg`.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_wind_avg(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)?.freshest_wind_avg()
+    }
+
+    /// Retrieve the most recent wind speed gust of a cached station based on the provided station's serial number
+    ///
+    /// The value is read from whichever of the station's observation, sky observation or rapid wind samples is
+    /// newest, see `Station::freshest_wind_gust`.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_wind_gust(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)?.freshest_wind_gust()
+    }
+
+    /// Retrieve the most recent wind direction of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_wind_direction(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.wind_direction)?
+    }
+
+    /// Retrieve the most recent wind speed of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_wind_speed(&self, serial_number: &str) -> Option<f32> {
+        Some(
+            self.get_station_by_sn(serial_number)?
+                .wind_event?
+                .get_wind_speed_mps(),
+        )
+    }
+
+    /// Retrieve the most recent wind direction as a point of the 16-point compass rose, e.g. "NNE", of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_wind_cardinal(&self, serial_number: &str) -> Option<&'static str> {
+        self.get_station_by_sn(serial_number)?
+            .wind_direction_cardinal()
+    }
+
+    /// Retrieve the clock skew (s, seconds) between a cached station and the local system based on the provided station's serial number
+    ///
+    /// The skew is the event timestamp minus the local time the event was received, so a positive value means the
+    /// station's clock is ahead. It is taken from the most recent event reporting a timestamp, whether a station,
+    /// air or sky observation, rapid wind, rain, lightning or device status event.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn clock_skew(&self, serial_number: &str) -> Option<i64> {
+        self.get_station_by_sn(serial_number)?.clock_skew
+    }
+
+    /// Retrieve the local time the most recent event from a cached station was received based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn station_last_seen(&self, serial_number: &str) -> Option<SystemTime> {
+        self.get_station_by_sn(serial_number)?.last_seen
+    }
+
+    /// Whether a cached station or hub has been heard from within `max_age` based on the provided serial number
+    ///
+    /// Liveness is based on the local time the most recent event was received according to the configured clock,
+    /// matching `evict_stale`.
+    ///
+    /// Returns the value as a Some(..) if the station or hub is cached otherwise returns a None
+    pub fn is_online(&self, serial_number: &str, max_age: Duration) -> Option<bool> {
+        let last_seen = match self.get_station_by_sn(serial_number) {
+            Some(station) => station.last_seen,
+            None => self.get_hub_by_sn(serial_number)?.last_seen,
+        }?;
+
+        Some(self.now().duration_since(last_seen).unwrap_or_default() <= max_age)
+    }
+
+    /// Retrieve the local time a station or hub was first added to the cache based on the provided serial number
+    ///
+    /// Unlike the last seen time this is not moved by later events, so it tells long-lived stations and hubs apart
+    /// from newly appeared ones.
+    ///
+    /// Returns the value as a Some(..) if the station or hub is cached otherwise returns a None
+    pub fn first_seen(&self, serial_number: &str) -> Option<SystemTime> {
+        match self.get_station_by_sn(serial_number) {
+            Some(station) => station.first_seen,
+            None => self.get_hub_by_sn(serial_number)?.first_seen,
+        }
+    }
+
+    /// Retrieve the most recent wind regime (calm, steady, or gusty) of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn wind_regime(&self, serial_number: &str) -> Option<WindRegime> {
+        self.get_station_by_sn(serial_number)?
+            .observation?
+            .wind_regime()
+            .ok()
+    }
+
+    /// Retrieve the frost risk of a cached station based on the provided station's serial number
+    ///
+    /// See `Station::frost_risk` for how the risk is estimated.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn frost_risk(&self, serial_number: &str) -> Option<FrostRisk> {
+        self.get_station_by_sn(serial_number)?.frost_risk()
+    }
+
+    /// Retrieve the drift between the actual and reported observation interval of a cached station based on the provided station's serial number
+    ///
+    /// The drift is the ratio of the time elapsed between receiving the last two observations to the report interval
+    /// stated by the most recent observation. Values well above 1.0 indicate delayed observations, e.g. RF congestion.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn report_interval_drift(&self, serial_number: &str) -> Option<f32> {
+        let station = self.get_station_by_sn(serial_number)?;
+        let arrival_interval = station.observation_arrival_interval?;
+        let report_interval = station.observation?.get_report_interval().ok()? * 60.0;
+
+        if report_interval > 0.0 {
+            Some(arrival_interval / report_interval)
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve the most recent station pressure (MB, millibars) of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_station_pressure(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.station_pressure)?
+    }
+
+    /// Retrieve the most recent air temperature (C, celsius) of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_air_temperature(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.air_temperature)?
+    }
+
+    /// Retrieve the exponential moving average of air temperature (C, celsius) of a cached station based on the provided station's serial number
+    ///
+    /// `alpha` weights each new reading against the running average and is clamped to 0.0 to 1.0,
+    /// where smaller values produce a smoother but more lagging average. The average is computed on each call from
+    /// only the most recent `EMA_SAMPLE_CAPACITY` readings, seeded from the oldest of them.
+    /// See `get_air_temperature_smoothed` for an average spanning every reading.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_air_temperature_ema(&self, serial_number: &str, alpha: f32) -> Option<f32> {
+        exponential_moving_average(
+            &self
+                .get_station_by_sn(serial_number)?
+                .air_temperature_samples,
+            alpha,
+        )
+    }
+
+    /// Retrieve the smoothed air temperature (C, celsius) of a cached station based on the provided station's serial number
+    ///
+    /// Unlike `get_air_temperature_ema` the average is kept up to date as each observation or air event arrives,
+    /// spanning every reading rather than only the most recent `EMA_SAMPLE_CAPACITY`, weighted by
+    /// `TempestBuilder::air_temperature_smoothing`.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_air_temperature_smoothed(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.air_temperature_smoothed)?
+    }
+
+    /// Retrieve the exponential moving average of station pressure (MB, millibars) of a cached station based on the provided station's serial number
+    ///
+    /// `alpha` weights each new reading against the running average and is clamped to 0.0 to 1.0,
+    /// where smaller values produce a smoother but more lagging average. The average is computed on each call from
+    /// only the most recent `EMA_SAMPLE_CAPACITY` readings, seeded from the oldest of them.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_station_pressure_ema(&self, serial_number: &str, alpha: f32) -> Option<f32> {
+        exponential_moving_average(
+            &self
+                .get_station_by_sn(serial_number)?
+                .station_pressure_samples,
+            alpha,
+        )
+    }
+
+    /// Retrieve the exponential moving average of relative humidity (%) of a cached station based on the provided station's serial number
+    ///
+    /// `alpha` weights each new reading against the running average and is clamped to 0.0 to 1.0,
+    /// where smaller values produce a smoother but more lagging average. The average is computed on each call from
+    /// only the most recent `EMA_SAMPLE_CAPACITY` readings, seeded from the oldest of them.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_relative_humidity_ema(&self, serial_number: &str, alpha: f32) -> Option<f32> {
+        exponential_moving_average(
+            &self
+                .get_station_by_sn(serial_number)?
+                .relative_humidity_samples,
+            alpha,
+        )
+    }
+
+    /// Retrieve the last `points` readings of a metric of a cached station based on the provided station's serial number,
+    /// newest last, for drawing a sparkline
+    ///
+    /// Histories shorter than `points` are padded at the front with their oldest reading.
+    ///
+    /// Returns the values as a Some(..) if present otherwise returns a None
+    pub fn sparkline(
+        &self,
+        serial_number: &str,
+        metric: SampledMetric,
+        points: usize,
+    ) -> Option<Vec<f32>> {
+        let station = self.get_station_by_sn(serial_number)?;
+        let samples = station.samples(metric);
+        let oldest = *samples.front()?;
+
+        let padding = points.saturating_sub(samples.len());
+        let skip = samples.len().saturating_sub(points);
+
+        Some(
+            std::iter::repeat_n(oldest, padding)
+                .chain(samples.iter().skip(skip).copied())
+                .collect(),
+        )
+    }
+
+    /// Retrieve the accumulated cooling and heating degree minutes (C·min) of a cached station based on the provided station's serial number
+    ///
+    /// Each observation contributes the difference between its air temperature and `base_c` (C, celsius),
+    /// scaled by its report interval, to the cooling total when above the base and the heating total when below.
+    ///
+    /// Returns the values as a Some((cooling, heating)) if present otherwise returns a None
+    pub fn degree_minutes(&self, serial_number: &str, base_c: f32) -> Option<(f32, f32)> {
+        let station = self.get_station_by_sn(serial_number)?;
+
+        if station.temperature_minutes.is_empty() {
+            return None;
+        }
+
+        Some((
+            station.cooling_degree_minutes(base_c),
+            station.heating_degree_minutes(base_c),
+        ))
+    }
+
+    /// Retrieve the dew point (C, celsius) from the most recent air temperature and relative humidity of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_dew_point(&self, serial_number: &str) -> Option<f32> {
+        let station = self.get_station_by_sn(serial_number)?;
+
+        Some(magnus_dew_point(
+            station.air_temperature?,
+            station.relative_humidity?,
+        ))
+    }
+
+    /// Retrieve the mixing ratio (g/kg) from the most recent air temperature, relative humidity and station pressure of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_mixing_ratio(&self, serial_number: &str) -> Option<f32> {
+        let station = self.get_station_by_sn(serial_number)?;
+
+        Some(mixing_ratio(
+            station.air_temperature?,
+            station.relative_humidity?,
+            station.station_pressure?,
+        ))
+    }
+
+    /// Retrieve the specific humidity (g/kg) from the most recent air temperature, relative humidity and station pressure of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_specific_humidity(&self, serial_number: &str) -> Option<f32> {
+        let station = self.get_station_by_sn(serial_number)?;
+
+        Some(specific_humidity(
+            station.air_temperature?,
+            station.relative_humidity?,
+            station.station_pressure?,
+        ))
+    }
+
+    /// Retrieve the potential temperature (C, celsius) from the most recent air temperature and station pressure of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_potential_temperature(&self, serial_number: &str) -> Option<f32> {
+        let station = self.get_station_by_sn(serial_number)?;
+
+        Some(potential_temperature(
+            station.air_temperature?,
+            station.station_pressure?,
+        ))
+    }
+
+    /// Retrieve the most recent illuminance (lux) of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_lux(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.illuminance)?
+    }
+
+    /// Retrieve the most recent UV Index of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_uv(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.uv)?
+    }
+
+    /// Retrieve the most recent solar radiation (W/m^2) of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_solar_radiation(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.solar_radiation)?
+    }
+
+    /// Retrieve the most recent photosynthetically active radiation (µmol·m⁻²·s⁻¹) of a cached station based on the provided station's serial number
+    ///
+    /// Approximated from solar radiation, see `solar_radiation_to_par`.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_par(&self, serial_number: &str) -> Option<f32> {
+        self.get_solar_radiation(serial_number)
+            .map(solar_radiation_to_par)
+    }
+
+    /// Retrieve the most recent measurement of rain (mm) in the previous minute of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_rain_prev_min(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.rain_amount_prev_minute)?
+    }
+
+    /// Retrieve the timestamp of the previous rain start from a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_prev_rain_start(&self, serial_number: &str) -> Option<u64> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.prev_rain_timestamp)?
+    }
+
+    /// Retrieve the rain (mm, millimeters) accumulated over the local day, as reported by a cached station's most
+    /// recent sky observation
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_local_day_rain_accum(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.local_day_rain_accum)?
+    }
+
+    /// Retrieve the rain (mm, millimeters) accumulated by a cached station since it was cached or last reset
+    ///
+    /// Each station observation and sky observation adds its previous minute's rain. Observations at or before the
+    /// newest one already counted, such as a resent or late packet, are not counted again.
+    ///
+    /// Returns the value as a Some(..) if the station is cached otherwise returns a None
+    pub fn get_rain_accumulated(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.rain_accumulated)
+    }
+
+    /// Restart the rain accumulation of a cached station from zero, such as at the start of a day
+    pub fn reset_rain_accumulation(&self, serial_number: &str) {
+        if let Some(station) = self
+            .write_inner()
+            .stations_cached
+            .get_mut(&normalize_serial_number(serial_number))
+        {
+            station.rain_accumulated = 0.0;
+        }
+    }
+
+    /// Retrieve the most recent precipitation type of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_precipitation_type(&self, serial_number: &str) -> Option<PrecipitationType> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.precipitation_type)?
+    }
+
+    /// Whether a cached station's most recent precipitation type reports any precipitation based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn is_precipitating(&self, serial_number: &str) -> Option<bool> {
+        self.get_station_by_sn(serial_number)?.is_precipitating()
+    }
+
+    /// Retrieve the most recent measurement of lightning strike average distance (km) of a cached station based on the provided station's serial number
+    ///
+    /// This is the average over an observation's report interval, see `get_lightning_distance` for the distance
+    /// of the most recent individual strike.
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_lightning_avg_distance(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.lightning_strike_avg_distance)?
+    }
+
+    /// Retrieve the most recent measurement of lightning strike average distance (mi, miles) of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_lightning_avg_distance_miles(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)?
+            .lightning_distance_miles()
+    }
+
+    /// Retrieve the most recent lightning strike count of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_lightning_count(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)
+            .map(|station| station.lightning_strike_count)?
+    }
+
+    /// Retrieve the most recent lightning strike timestamp of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_lightning_timestamp(&self, serial_number: &str) -> Option<u64> {
+        self.get_station_by_sn(serial_number)?
+            .lightning_event?
+            .try_get_timestamp()
+            .ok()
+    }
+
+    /// Retrieve the most recent lightning strike distance (km, kilometers) of a cached station based on the provided station's serial number
+    ///
+    /// This is the distance of the most recent strike event, see `get_lightning_avg_distance` for the average
+    /// reported by observations.
+    ///
+    /// Returns the value as a Some(..) if the station has reported a strike otherwise returns a None
+    pub fn get_lightning_distance(&self, serial_number: &str) -> Option<u64> {
+        self.get_station_by_sn(serial_number)?
+            .lightning_event?
+            .try_get_strike_distance()
+            .ok()
+    }
+
+    /// Retrieve the most recent lightning strike distance (mi, miles) of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if the station has reported a strike otherwise returns a None
+    pub fn get_last_strike_distance_miles(&self, serial_number: &str) -> Option<f32> {
+        self.get_station_by_sn(serial_number)?
+            .last_strike_distance_miles()
+    }
+
+    /// Retrieve the most recent lightning strike energy (J, joules) of a cached station based on the provided station's serial number
+    ///
+    /// Returns the value as a Some(..) if present otherwise returns a None
+    pub fn get_lightning_energy(&self, serial_number: &str) -> Option<u64> {
+        self.get_station_by_sn(serial_number)?
+            .lightning_event?
+            .try_get_strike_energy()
+            .ok()
+    }
+
+    /// Retrieve the total energy of the lightning strikes recorded by a cached station within the last `window`
+    ///
+    /// Returns the value as a Some(..) if the station has recorded a strike otherwise returns a None
+    pub fn lightning_energy_total(&self, serial_number: &str, window: Duration) -> Option<u64> {
+        let station = self.get_station_by_sn(serial_number)?;
+
+        Some(
+            recent_strikes(&station, window, self.now())?
+                .filter_map(|strike| strike.try_get_strike_energy().ok())
+                .sum(),
+        )
+    }
+
+    /// Retrieve the most energetic lightning strike recorded by a cached station within the last `window`
+    ///
+    /// Returns the value as a Some(..) if the station has recorded a strike within the window otherwise returns a None
+    pub fn strongest_lightning(
+        &self,
+        serial_number: &str,
+        window: Duration,
+    ) -> Option<LightningStrikeEvent> {
+        let station = self.get_station_by_sn(serial_number)?;
+
+        recent_strikes(&station, window, self.now())?
+            .max_by_key(|strike| strike.try_get_strike_energy().unwrap_or_default())
+            .cloned()
+    }
+
+    /// Retrieve the flash rate (strikes per minute) of a cached station over the last `window`, falling to zero once
+    /// a storm has passed
+    ///
+    /// Returns the value as a Some(..) if the station has recorded a strike and the window is not empty otherwise
+    /// returns a None
+    pub fn lightning_flash_rate(&self, serial_number: &str, window: Duration) -> Option<f32> {
+        if window.is_zero() {
+            return None;
+        }
+
+        let station = self.get_station_by_sn(serial_number)?;
+        let strikes = recent_strikes(&station, window, self.now())?.count();
+
+        Some(strikes as f32 / (window.as_secs_f32() / 60.0))
+    }
+
+    /// Retrieve a value from a cached station, distinguishing an unknown station from a missing value
+    fn try_get_station_value<T>(
+        &self,
+        serial_number: &str,
+        value: impl FnOnce(Station) -> Option<T>,
+    ) -> Result<T, LookupError> {
+        let station = self
+            .get_station_by_sn(serial_number)
+            .ok_or(LookupError::StationNotFound)?;
+
+        value(station).ok_or(LookupError::FieldUnavailable)
+    }
+
+    /// Retrieve the most recent battery voltage of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_battery_voltage(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.battery_voltage)
+    }
+
+    /// Retrieve the most recent wind speed lull of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_wind_lull(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.wind_lull)
+    }
+
+    /// Retrieve the most recent wind speed average of a cached station based on the provided station's serial number,
+    /// see `get_wind_avg`
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_wind_avg(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.freshest_wind_avg())
+    }
+
+    /// Retrieve the most recent wind speed gust of a cached station based on the provided station's serial number,
+    /// see `get_wind_gust`
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_wind_gust(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.freshest_wind_gust())
+    }
+
+    /// Retrieve the most recent wind direction of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_wind_direction(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.wind_direction)
+    }
+
+    /// Retrieve the most recent station pressure (MB, millibars) of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_station_pressure(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.station_pressure)
+    }
+
+    /// Retrieve the most recent air temperature (C, celsius) of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_air_temperature(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.air_temperature)
+    }
+
+    /// Retrieve the most recent illuminance (lux) of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_lux(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.illuminance)
+    }
+
+    /// Retrieve the most recent UV Index of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_uv(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.uv)
+    }
+
+    /// Retrieve the most recent solar radiation (W/m^2) of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_solar_radiation(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.solar_radiation)
+    }
+
+    /// Retrieve the most recent measurement of rain (mm) in the previous minute of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_rain_prev_min(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.rain_amount_prev_minute)
+    }
+
+    /// Retrieve the most recent measurement of lightning strike average distance (km) of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_lightning_avg_distance(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| {
+            station.lightning_strike_avg_distance
+        })
+    }
+
+    /// Retrieve the most recent lightning strike count of a cached station based on the provided station's serial number
+    ///
+    /// Returns Ok(..) if present, Err(LookupError::FieldUnavailable) if the station is cached without the value,
+    /// otherwise Err(LookupError::StationNotFound)
+    pub fn try_get_lightning_count(&self, serial_number: &str) -> Result<f32, LookupError> {
+        self.try_get_station_value(serial_number, |station| station.lightning_strike_count)
+    }
+
+    /// Listen to UDP packets sent from the WeatherFlow Tempest hub
+    ///
+    /// Returns a Tokio receiver containing a weather event as an `EventType`.
+    /// The `Tempest` instance is disregarded in this use case and the listener runs for the lifetime of the process.
+    ///
+    /// Returns an `Err` if the socket cannot be bound, such as when the port is already in use.
+    pub async fn listen_udp() -> io::Result<Receiver<EventType>> {
+        let (mut tempest, rx) = Tempest::builder().listen().await?;
+        tempest.detach();
+        Ok(rx)
+    }
+
+    /// Listen to UDP packets sent from the WeatherFlow Tempest hub and cache data about hubs and stations reporting events
+    ///
+    /// Returns a `Tempest` instance along with a Tokio receiver containining a weather event as an `EventType`.
+    /// The listener stops and releases its socket once every clone of the `Tempest` instance is dropped.
+    ///
+    /// Returns an `Err` if the socket cannot be bound, such as when the port is already in use.
+    pub async fn listen_udp_with_cache() -> io::Result<(Tempest, Receiver<EventType>)> {
+        Tempest::builder().cache(true).listen().await
+    }
+
+    /// Listen to UDP packets sent from the WeatherFlow Tempest hub and only share events that match the provided serial number.
+    ///
+    /// Returns a Tokio receiver accepting weather events as an `EventType`.
+    /// The `Tempest` instance is disregarded in this use case and the listener runs for the lifetime of the process.
+    ///
+    /// Returns an `Err` if the socket cannot be bound, such as when the port is already in use.
+    pub async fn listen_udp_subscribe(
+        station_filter: Vec<&str>,
+    ) -> io::Result<Receiver<EventType>> {
+        Tempest::listen_udp_subscribe_by(station_filter, FilterBy::Device).await
+    }
+
+    /// Listen to UDP packets sent from the WeatherFlow Tempest hub and only share events whose device or hub serial
+    /// number, as chosen by `filter_by`, matches the provided serial numbers
+    ///
+    /// Subscribing by `FilterBy::Hub` shares the events of every device reporting through the provided hubs.
+    /// The listener runs for the lifetime of the process.
+    ///
+    /// Returns an `Err` if the socket cannot be bound, such as when the port is already in use.
+    pub async fn listen_udp_subscribe_by(
+        station_filter: Vec<&str>,
+        filter_by: FilterBy,
+    ) -> io::Result<Receiver<EventType>> {
+        let (mut tempest, rx) = Tempest::builder()
+            .filter(station_filter)
+            .filter_by(filter_by)
+            .listen()
+            .await?;
+        tempest.detach();
+        Ok(rx)
+    }
+
+    /// Listen to UDP packets sent from the WeatherFlow Tempest hub on the provided address and port
+    ///
+    /// `address` may be IPv4 or IPv6 and defaults to `0.0.0.0` (every IPv4 interface), and `port` defaults to `50222`
+    /// when None. See `listen_udp_internal` for how `caching` and `station_filter` are applied.
+    ///
+    /// Returns a `Tempest` instance along with a Tokio receiver containining a weather event as an `EventType`.
+    /// The listener stops and releases its socket once every clone of the `Tempest` instance is dropped.
+    ///
+    /// Returns an `Err` if the socket cannot be bound, such as when the port is already in use.
+    pub async fn listen_udp_on(
+        address: Option<IpAddr>,
+        port: Option<u16>,
+        caching: bool,
+        station_filter: Option<Vec<String>>,
+    ) -> io::Result<(Tempest, Receiver<EventType>)> {
+        Tempest::listen_udp_internal(address, port, caching, station_filter).await
+    }
+
+    /// Listen to UDP packets sent from the WeatherFlow Tempest hub and share them with any number of subscribers
+    ///
+    /// Returns a Tokio broadcast receiver containing a weather event as an `EventType`, further receivers can be created
+    /// with `resubscribe`. A receiver that falls more than 64 events behind skips the oldest events it has missed and
+    /// is told how many with `RecvError::Lagged` on its next receive.
+    /// The listener runs for the lifetime of the process.
+    ///
+    /// Returns an `Err` if the socket cannot be bound, such as when the port is already in use.
+    pub async fn listen_udp_broadcast() -> io::Result<broadcast::Receiver<EventType>> {
+        let (mut tempest, _rx) = Tempest::builder().listen().await?;
+        let receiver = tempest.subscribe();
+        tempest.detach();
+        Ok(receiver)
+    }
+
+    /// Listen to UDP packets sent from the WeatherFlow Tempest hub to a multicast group
+    ///
+    /// Joins the multicast `group` on the interface with the provided `interface` address, such as `0.0.0.0` to let the
+    /// system choose. Returns a Tokio receiver containing a weather event as an `EventType`.
+    /// The listener runs for the lifetime of the process.
+    ///
+    /// Returns an `Err` if the socket cannot be bound or the group cannot be joined.
+    pub async fn listen_udp_multicast(
+        group: Ipv4Addr,
+        interface: Ipv4Addr,
+    ) -> io::Result<Receiver<EventType>> {
+        let (mut tempest, rx) = Tempest::builder()
+            .multicast(group, interface)
+            .listen()
+            .await?;
+        tempest.detach();
+        Ok(rx)
+    }
+
+    /// Listen to UDP packets sent from the WeatherFlow Tempest hub as a stream of events
+    ///
+    /// Returns a Tokio stream of weather events as an `EventType`, which can be composed with the `StreamExt`
+    /// combinators such as `filter`, `take` or `throttle`. The listener runs for the lifetime of the process.
+    ///
+    /// Returns an `Err` if the socket cannot be bound, such as when the port is already in use.
+    pub async fn listen_udp_stream() -> io::Result<ReceiverStream<EventType>> {
+        Tempest::listen_udp().await.map(ReceiverStream::new)
+    }
+
+    /// Internal function used for parsing UDP packets containing JSON weather data.
+    ///
+    /// When a weather event is received, a few things can happen depending on the parameters passed into this function.
+    ///
+    /// If `caching` is set to true then the weather event will be saved to cache.
+    ///
+    /// If `station_filter` is Some(..) and contains station serial numbers then it will only send the weather event
+    /// back over the mpsc channel if the weather event's serial number matches the provided serial number.
+    /// This acts like a form of filtering. The hub serial number is matched instead when filtering by `FilterBy::Hub`.
+    ///
+    /// This function returns both an instance of `Tempest` for further weather data retrieval (air temperature, wind, etc)
+    /// and `rx` is an mpsc receiver for accepting weather event data as it arrives. The returned `Tempest` holds the
+    /// `ListenHandle` which stops the listener task once every clone of it is dropped. Other options are left at their
+    /// `TempestBuilder` defaults.
+    ///
+    /// Returns an `Err` if the socket cannot be bound.
+    async fn listen_udp_internal(
+        address: Option<IpAddr>,
+        port: Option<u16>,
+        caching: bool,
+        station_filter: Option<Vec<String>>,
+    ) -> io::Result<(Tempest, Receiver<EventType>)> {
+        let station_filter = station_filter.map(|stations| {
+            stations
+                .iter()
+                .map(|station| normalize_serial_number(station))
+                .collect()
+        });
+
+        TempestBuilder {
+            address,
+            port,
+            caching,
+            station_filter,
+            ..TempestBuilder::default()
+        }
+        .listen()
+        .await
+    }
+
+    /// Spawn the task receiving and parsing UDP packets on the bound socket
+    ///
+    /// See `listen_udp_internal` for how `caching` and the station filter are applied, the filter being read for
+    /// each event so it can be changed with `set_filter` and `clear_filter`.
+    fn spawn_listener(self, caching: bool) -> (Tempest, Receiver<EventType>) {
+        let mut tempest = self;
+
+        let (policy, capacity, store_raw_json) = {
+            let inner = tempest.read_inner();
+            (
+                inner.overflow_policy,
+                inner.channel_capacity,
+                inner.store_raw_json,
+            )
+        };
+        let (sink, rx) = EventSink::new(policy, capacity);
+
+        let shutdown = Arc::new(Notify::new());
+
+        let mut tempest_clone: Tempest = tempest.clone();
+        let signal = shutdown.clone();
+
+        let task = tokio::spawn(async move {
+            loop {
+                let buffer_size = tempest.read_inner().buffer_size;
+                let mut recv_buffer: Vec<u8> = vec![0; buffer_size];
+
+                // receive udp packet into buffer unless signalled to stop
+                let len = tokio::select! {
+                    _ = signal.notified() => {
+                        trace!("Stopping listener task");
+                        break;
+                    }
+                    result = tempest.recv.recv_from(&mut recv_buffer) => match result {
+                        Ok((len, _addr)) => len,
+                        Err(e) => {
+                            warn!("Failed to receive UDP packet: {e}");
+                            continue;
+                        }
+                    },
+                };
+
+                tempest.write_inner().packets_received += 1;
+
+                // a packet filling the buffer has likely lost its tail
+                if len == buffer_size {
+                    warn!(
+                        "UDP packet filled the {buffer_size} byte receive buffer and may have been truncated"
+                    );
+                    tempest.write_inner().truncated_packets += 1;
+                }
+
+                // deserialize buffer contents into json value
+                let json: Value = match serde_json::from_slice(&recv_buffer[0..len]) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        debug!("Failed to deserialize packet contents into serde JSON value: {e}");
+                        tempest.write_inner().parse_errors += 1;
+                        continue;
+                    }
+                };
+
+                // keep the raw JSON of the packet, the setting being read once as it cannot change while listening
+                if store_raw_json && let Some(serial_number) = json["serial_number"].as_str() {
+                    tempest.write_inner().raw_json.insert(
+                        normalize_serial_number(serial_number),
+                        String::from_utf8_lossy(&recv_buffer[0..len]).into_owned(),
+                    );
+                }
+
+                let mut event = match EventType::from_json(&json) {
+                    Ok(event) => {
+                        if let Some(event_type) = json["type"].as_str() {
+                            *tempest
+                                .write_inner()
+                                .event_counts
+                                .entry(event_type.to_string())
+                                .or_default() += 1;
+                        }
+
+                        event
+                    }
+                    Err(EventError::UnknownEventType) => {
+                        let handler = {
+                            let mut inner = tempest.write_inner();
+                            inner.unknown_events += 1;
+                            inner.unknown_handler.clone()
+                        };
+
+                        match (json["type"].as_str(), handler) {
+                            (Some(event_type), Some(handler)) => handler(event_type, &json),
+                            _ => debug!("Unknown event type received"),
+                        }
+
+                        // share unsupported events that are tagged with a type
+                        match json["type"].as_str().map(str::to_string) {
+                            Some(event_type) => EventType::Unknown {
+                                event_type,
+                                raw: json,
+                            },
+                            None => continue,
+                        }
+                    }
+                    Err(_) => {
+                        tempest.write_inner().parse_errors += 1;
+                        continue;
+                    }
+                };
+
+                event.normalize_serial_numbers();
+
+                let valid = tempest.validated(event.validate());
+
+                if caching && valid {
+                    tempest.cache_event(event.clone());
+                }
+
+                if valid {
+                    tempest.detect_precip_transition(&event);
+                }
+
+                if !valid && tempest.read_inner().drop_invalid_events {
+                    continue;
+                }
+
+                // send event if no serial number provided or on a match
+                let matched = {
+                    let inner = tempest.read_inner();
+
+                    inner.station_filter.as_ref().is_none_or(|stations| {
+                        stations.contains(&match inner.filter_by {
+                            FilterBy::Device => event.get_serial_number(),
+                            FilterBy::Hub => event.get_hub_sn(),
+                        })
+                    })
+                };
+
+                if matched {
+                    let handlers = tempest.read_inner().event_handlers.clone();
+
+                    for handler in handlers {
+                        handler(&event);
+                    }
+
+                    // a blocking send waits on the receiver, so it must not hold off a signal to stop
+                    tokio::select! {
+                        _ = signal.notified() => {
+                            trace!("Stopping listener task");
+                            break;
+                        }
+                        _ = tempest.share_event(&sink, event) => {}
+                    }
+                }
+            }
+        });
+
+        tempest_clone.handle = Some(Arc::new(ListenHandle {
+            shutdown: Some(shutdown),
+            task: Mutex::new(Some(task)),
+        }));
+
+        (tempest_clone, rx)
+    }
+}
+
+/// Lightning strikes recorded by a station within `window` before `now`, None if it has recorded none
+fn recent_strikes(
+    station: &Station,
+    window: Duration,
+    now: SystemTime,
+) -> Option<impl Iterator<Item = &LightningStrikeEvent>> {
+    if station.lightning_history.is_empty() {
+        return None;
+    }
+
+    let start = now
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        .saturating_sub(window.as_secs());
+
+    Some(
+        station
+            .lightning_history
+            .iter()
+            .filter(move |strike| strike.try_get_timestamp().is_ok_and(|time| time >= start)),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::clock::TestClock;
+    use crate::mock::{MockHub, MockHubConfig, MockSender, replay_file};
+    use crate::test_common::*;
+    use std::net::Ipv6Addr;
+    use std::sync::atomic::AtomicUsize;
+
+    async fn test_setup(caching: bool) -> (MockSender, Tempest, Receiver<EventType>, u16) {
+        let mock = MockSender::bind();
+
+        let (tempest, receiver) =
+            Tempest::listen_udp_internal(Some(Ipv4Addr::LOCALHOST.into()), Some(0), caching, None)
+                .await
+                .expect("Unable to bind listener");
+
+        let port: u16 = tempest
+            .recv
+            .local_addr()
+            .expect("Unable to retrieve local address of listener")
+            .port();
+
+        (mock, tempest, receiver, port)
+    }
+
+    #[tokio::test]
+    async fn station_count() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+
+        // check cached station count while empty
+        assert_eq!(0, tempest.station_count());
+
+        // check cached station count after receiving a station observation event
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+        assert_eq!(1, tempest.station_count());
+
+        // check cached station count after receiving a station observation event
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+        assert_eq!(1, tempest.station_count());
+    }
+
+    #[tokio::test]
+    async fn remove_cached() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        mock.send(get_station_observation_payload(), port);
+        receiver.recv().await;
+        mock.send(
+            get_station_observation_payload_from("ST-00000513", STATION_OBSERVATION),
+            port,
+        );
+        receiver.recv().await;
+        mock.send(get_hub_payload(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.station_count(), 2);
+        assert_eq!(tempest.hub_count(), 1);
+
+        assert!(tempest.remove_station("st-00000513"));
+        assert!(!tempest.remove_station("ST-00000513"));
+        assert_eq!(tempest.station_count(), 1);
+        assert!(tempest.get_station_by_sn("ST-00000512").is_some());
+
+        assert!(tempest.remove_hub("HB-00013030"));
+        assert!(!tempest.remove_hub("HB-00013030"));
+        assert_eq!(tempest.hub_count(), 0);
+        assert_eq!(tempest.station_count(), 1);
+
+        mock.send(get_hub_payload(), port);
+        receiver.recv().await;
+        assert_eq!(tempest.hub_count(), 1);
+
+        tempest.clear_cache();
+        assert_eq!(tempest.station_count(), 0);
+        assert_eq!(tempest.hub_count(), 0);
+
+        // stations are cached afresh after clearing
+        mock.send(get_station_observation_payload(), port);
+        receiver.recv().await;
+        assert_eq!(tempest.station_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn hub_count() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_hub_payload();
+
+        // check cached hub count while empty
+        assert_eq!(0, tempest.hub_count());
+
+        // check cached hub count after receiving a hub status event
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+        assert_eq!(1, tempest.hub_count());
+
+        // check cached hub count after receiving a hub status event for the same hub
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+        assert_eq!(1, tempest.hub_count());
+    }
+
+    #[tokio::test]
+    async fn hub_with_short_radio_stats() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = serde_json::to_vec(&serde_json::json!({
+            "serial_number": "HB-00013030",
+            "type": "hub_status",
+            "firmware_revision": "35",
+            "uptime": 1670133,
+            "rssi": -62,
+            "timestamp": 1495724691,
+            "reset_flags": "BOR,PIN,POR",
+            "seq": 48,
+            "radio_stats": [2, 1, 0],
+            "mqtt_stats": [1, 0]
+        }))
+        .expect("Failed to convert JSON to vector");
+
+        mock.send(payload, port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.hub_count(), 1);
+        assert_eq!(tempest.metrics().rejected_events, 0);
+
+        let hub = tempest
+            .get_hub_by_sn("HB-00013030")
+            .expect("Unable to retrieve hub");
+        assert_eq!(hub.radio_stats.version, 2);
+    }
+
+    #[tokio::test]
+    async fn get_hub_by_sn() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_hub_payload();
+
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // try to retrieve hub with correct SN
+        let hub = tempest.get_hub_by_sn("HB-00013030");
+
+        assert!(hub.is_some());
+
+        // try to retrieve hub with incorrect SN
+        let hub = tempest.get_hub_by_sn("HB-00000000");
+
+        assert!(hub.is_none())
+    }
+
+    #[tokio::test]
+    async fn get_hub_from_station() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_hub_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let station = tempest
+            .get_station_by_sn("ST-00000512")
+            .expect("Unable to retrieve station");
+
+        let hub = tempest.get_hub_from_station(station);
+
+        assert!(hub.is_some());
+    }
+
+    #[tokio::test]
+    async fn get_station_by_sn() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // try to retrieve station with correct SN
+        let station = tempest.get_station_by_sn("ST-00000512");
+
+        assert!(station.is_some());
+
+        // try to retrieve hub with incorrect SN
+        let station = tempest.get_station_by_sn("ST-00000513");
+
+        assert!(station.is_none())
+    }
+
+    #[tokio::test]
+    async fn get_stations_by_hub_sn() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        // cache hub
+        let payload = get_hub_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // cache station 1
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // cache station 2
+        let payload = get_secondary_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let stations = tempest.get_stations_by_hub_sn("HB-00013030");
+
+        assert_eq!(stations.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn cache_rain_event_only() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_rain_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_prev_rain_start("ST-00000512"), Some(1493322445));
+    }
+
+    #[tokio::test]
+    async fn cache_air_event_only() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_air_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_air_temperature("ST-00000512"), Some(10.0));
+    }
+
+    #[tokio::test]
+    async fn cache_sky_event_only() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_sky_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        println!("Assert");
+        assert_eq!(tempest.get_lux("ST-00000512"), Some(9000.0));
+    }
+
+    #[tokio::test]
+    async fn cache_wind_event_only() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_speed("ST-00000512"), Some(2.3));
+    }
+
+    #[tokio::test]
+    async fn cache_lightning_event_only() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_lightning_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_lightning_energy("ST-00000512"), Some(3848));
+    }
+
+    #[tokio::test]
+    async fn get_battery_voltage() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_battery_voltage("ST-00000512"), Some(2.410));
+    }
+
+    #[tokio::test]
+    async fn get_wind_lull() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_lull("ST-00000512"), Some(0.18));
+    }
+
+    #[tokio::test]
+    async fn get_wind_avg() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_avg("ST-00000512"), Some(0.22));
+    }
+
+    #[tokio::test]
+    async fn get_wind_gust() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_gust("ST-00000512"), Some(0.27));
+    }
+
+    #[tokio::test]
+    async fn get_wind_direction() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_direction("ST-00000512"), Some(144.0));
+    }
+
+    #[tokio::test]
+    async fn get_wind_speed() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_speed("ST-00000512"), Some(2.3));
+    }
+
+    #[tokio::test]
+    async fn get_station_pressure() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_station_pressure("ST-00000512"), Some(1017.57));
+    }
+
+    #[tokio::test]
+    async fn get_air_temperature() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_air_temperature("ST-00000512"), Some(22.37));
+    }
+
+    #[tokio::test]
+    async fn get_lux() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_lux("ST-00000512"), Some(328.0));
+    }
+
+    #[tokio::test]
+    async fn get_uv() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_uv("ST-00000512"), Some(0.03));
+    }
+
+    #[tokio::test]
+    async fn get_solar_radiation() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_solar_radiation("ST-00000512"), Some(3.0));
+    }
+
+    #[tokio::test]
+    async fn get_rain_prev_min() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_rain_prev_min("ST-00000512"), Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn get_precip_type() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.get_precipitation_type("ST-00000512"),
+            Some(PrecipitationType::None)
+        );
+    }
+
+    #[tokio::test]
+    async fn is_precipitating() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let mut obs = STATION_OBSERVATION;
+
+        for (precip_type, precipitating) in [(0.0, false), (1.0, true), (2.0, true), (3.0, true)] {
+            obs[13] = precip_type;
+            let payload = get_station_observation_payload_from("ST-00000512", obs);
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+
+            assert_eq!(tempest.is_precipitating("ST-00000512"), Some(precipitating));
+        }
+
+        assert_eq!(tempest.is_precipitating("ST-00000513"), None);
+    }
+
+    #[tokio::test]
+    async fn get_lightning_avg_distance() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_lightning_avg_distance("ST-00000512"), Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn get_lightning_count() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_lightning_count("ST-00000512"), Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn get_lightning_timestamp() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_lightning_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.get_lightning_timestamp("ST-00000512"),
+            Some(1493322445)
+        );
+    }
+
+    #[tokio::test]
+    async fn get_lightning_distance() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_lightning_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_lightning_distance("ST-00000512"), Some(27));
+    }
+
+    #[tokio::test]
+    async fn get_lightning_energy() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_lightning_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_lightning_energy("ST-00000512"), Some(3848));
+    }
+
+    #[tokio::test]
+    async fn signal_strength_stations() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_device_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_secondary_device_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.weakest_signal_station(),
+            Some(("AR-00004050".to_string(), -72))
+        );
+        assert_eq!(
+            tempest.strongest_signal_station(),
+            Some(("AR-00004049".to_string(), -17))
+        );
+    }
+
+    #[tokio::test]
+    async fn hub_averages() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        assert_eq!(tempest.hub_average_temperature("HB-00013030"), None);
+
+        mock.send(get_station_observation_payload(), port);
+        receiver.recv().await;
+
+        let mut obs = STATION_OBSERVATION;
+        obs[6] = 1019.57;
+        obs[7] = 26.37;
+        obs[8] = 60.0;
+        mock.send(
+            get_station_observation_payload_from("ST-00000513", obs),
+            port,
+        );
+        receiver.recv().await;
+
+        let temperature = tempest
+            .hub_average_temperature("hb-00013030")
+            .expect("Unable to average temperature");
+        assert!((temperature - 24.37).abs() < 0.001);
+
+        let pressure = tempest
+            .hub_average_pressure("HB-00013030")
+            .expect("Unable to average pressure");
+        assert!((pressure - 1018.57).abs() < 0.01);
+
+        let humidity = tempest
+            .hub_average_humidity("HB-00013030")
+            .expect("Unable to average humidity");
+        assert!((humidity - (STATION_OBSERVATION[8] as f32 + 60.0) / 2.0).abs() < 0.001);
+
+        assert_eq!(tempest.hub_average_temperature("HB-00000002"), None);
+    }
+
+    #[tokio::test]
+    async fn get_air_temperature_smoothed() {
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .air_temperature_smoothing(0.5),
+        )
+        .await;
+
+        mock.send(get_station_observation_payload(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.get_air_temperature_smoothed("ST-00000512"),
+            Some(22.37)
+        );
+
+        // step change of 10 degrees, halving the gap with each reading
+        let mut obs = STATION_OBSERVATION;
+        obs[7] = 32.37;
+        let mut gap = 10.0;
+
+        for minute in 1..=6 {
+            obs[0] += 60.0 * minute as f64;
+            mock.send(
+                get_station_observation_payload_from("ST-00000512", obs),
+                port,
+            );
+            receiver.recv().await;
+
+            gap /= 2.0;
+            let smoothed = tempest
+                .get_air_temperature_smoothed("ST-00000512")
+                .expect("Unable to retrieve smoothed temperature");
+            assert!((smoothed - (32.37 - gap)).abs() < 0.001);
+        }
+
+        assert_eq!(tempest.get_air_temperature_smoothed("ST-00000513"), None);
+    }
+
+    #[tokio::test]
+    async fn get_air_temperature_ema() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // step change of 10 degrees
+        let mut obs = STATION_OBSERVATION;
+        obs[7] = 32.37;
+        let payload = get_station_observation_payload_from("ST-00000512", obs);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_air_temperature("ST-00000512"), Some(32.37));
+
+        let ema = tempest
+            .get_air_temperature_ema("ST-00000512", 0.25)
+            .expect("Unable to retrieve EMA");
+        assert!((ema - 24.87).abs() < 0.001);
+
+        let ema = tempest
+            .get_air_temperature_ema("ST-00000512", 0.5)
+            .expect("Unable to retrieve EMA");
+        assert!((ema - 27.37).abs() < 0.001);
+
+        assert_eq!(
+            tempest.get_air_temperature_ema("ST-00000512", 1.0),
+            Some(32.37)
+        );
+        assert_eq!(
+            tempest.get_station_pressure_ema("ST-00000512", 0.5),
+            Some(1017.57)
+        );
+        assert_eq!(
+            tempest.get_relative_humidity_ema("ST-00000512", 0.5),
+            Some(50.26)
+        );
+    }
+
+    #[tokio::test]
+    async fn wind_regime() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.wind_regime("ST-00000512"), Some(WindRegime::Calm));
+        assert_eq!(tempest.wind_regime("ST-00000513"), None);
+    }
+
+    #[tokio::test]
+    async fn drop_releases_socket() {
+        let port = std::net::UdpSocket::bind("127.0.0.1:0")
+            .and_then(|socket| socket.local_addr())
+            .expect("Unable to reserve a local port")
+            .port();
+
+        for _ in 0..3 {
+            let (tempest, receiver) = Tempest::listen_udp_internal(
+                Some(Ipv4Addr::LOCALHOST.into()),
+                Some(port),
+                true,
+                None,
+            )
+            .await
+            .expect("Unable to bind listener");
+
+            drop(tempest);
+            drop(receiver);
+
+            // give the listener task a chance to observe the signal and release the socket
+            let mut released = false;
+            for _ in 0..100 {
+                tokio::task::yield_now().await;
+
+                if std::net::UdpSocket::bind(("127.0.0.1", port)).is_ok() {
+                    released = true;
+                    break;
+                }
+            }
+
+            assert!(released);
+        }
+    }
+
+    async fn builder_setup(
+        builder: TempestBuilder,
+    ) -> (MockSender, Tempest, Receiver<EventType>, u16) {
+        let mock = MockSender::bind();
+
+        let (tempest, receiver) = builder
+            .address(Ipv4Addr::new(127, 0, 0, 1))
+            .port(0)
+            .listen()
+            .await
+            .expect("Unable to bind listener");
+
+        let port: u16 = tempest
+            .recv
+            .local_addr()
+            .expect("Unable to retrieve local address of listener")
+            .port();
+
+        (mock, tempest, receiver, port)
+    }
+
+    #[tokio::test]
+    async fn builder_filtered_cache() {
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .filter(vec!["ST-00000512"])
+                .buffer_size(2048),
+        )
+        .await;
+
+        assert_ne!(port, 0);
+
+        // filtered out of the channel but still cached
+        let payload = get_station_observation_payload_from("ST-00000513", STATION_OBSERVATION);
+        mock.send(payload.clone(), port);
+
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+
+        let event = receiver.recv().await.expect("Unable to receive event");
+        assert_eq!(event.get_serial_number(), "ST-00000512");
+
+        assert_eq!(tempest.station_serials(), ["ST-00000512", "ST-00000513"]);
+        assert!(
+            time::timeout(Duration::from_millis(100), receiver.recv())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn set_filter() {
+        let (mock, tempest, mut receiver, port) = test_setup(false).await;
+
+        let payload = get_rapidwind_payload_from("ST-00000512", [1493322445.0, 2.3, 128.0]);
+        mock.send(payload.clone(), port);
+        let event = receiver.recv().await.expect("Unable to receive event");
+        assert_eq!(event.get_serial_number(), "ST-00000512");
+
+        tempest.set_filter(vec!["st-00000513".to_string()]);
+
+        mock.send(payload.clone(), port);
+        let payload = get_rapidwind_payload_from("ST-00000513", [1493322445.0, 2.3, 128.0]);
+        mock.send(payload.clone(), port);
+
+        let event = receiver.recv().await.expect("Unable to receive event");
+        assert_eq!(event.get_serial_number(), "ST-00000513");
+        assert!(
+            time::timeout(Duration::from_millis(100), receiver.recv())
+                .await
+                .is_err()
+        );
+
+        tempest.clear_filter();
+
+        let payload = get_rapidwind_payload_from("ST-00000512", [1493322448.0, 2.3, 128.0]);
+        mock.send(payload.clone(), port);
+        let event = receiver.recv().await.expect("Unable to receive event");
+        assert_eq!(event.get_serial_number(), "ST-00000512");
+    }
+
+    #[tokio::test]
+    async fn hold_calm_wind_direction() {
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .hold_calm_wind_direction(true),
+        )
+        .await;
+
+        let payload = get_rapidwind_payload_from("ST-00000512", [1493322445.0, 2.3, 187.0]);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_direction("ST-00000512"), Some(187.0));
+
+        let payload = get_rapidwind_payload_from("ST-00000512", [1493322448.0, 0.0, 0.0]);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_direction("ST-00000512"), Some(187.0));
+    }
+
+    #[tokio::test]
+    async fn calm_wind_direction_not_held_by_default() {
+        let (mock, tempest, mut receiver, port) =
+            builder_setup(Tempest::builder().cache(true)).await;
+
+        let payload = get_rapidwind_payload_from("ST-00000512", [1493322445.0, 2.3, 187.0]);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_rapidwind_payload_from("ST-00000512", [1493322448.0, 0.0, 0.0]);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_direction("ST-00000512"), None);
+        assert_eq!(tempest.get_wind_speed("ST-00000512"), Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn stale_hubs() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        // hub with a status from 2017
+        let payload = get_hub_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // hub with a current status
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("System time before epoch")
+            .as_secs();
+        let payload = get_hub_payload_from("HB-00013031", now, [2, 1, 0, 3, 2840]);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.stale_hubs(Duration::from_secs(3600)),
+            vec!["HB-00013030".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn degree_minutes() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        // 22.37 C for one minute
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // 15.0 C for two minutes
+        let mut obs = STATION_OBSERVATION;
+        obs[7] = 15.0;
+        obs[17] = 2.0;
+        let payload = get_station_observation_payload_from("ST-00000512", obs);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let (cooling, heating) = tempest
+            .degree_minutes("ST-00000512", 18.0)
+            .expect("Unable to retrieve degree minutes");
+
+        assert!((cooling - 4.37).abs() < 0.001);
+        assert!((heating - 6.0).abs() < 0.001);
+        assert_eq!(tempest.degree_minutes("ST-00000513", 18.0), None);
+    }
+
+    #[tokio::test]
+    async fn try_get_unknown_station() {
+        let (_mock, tempest, _receiver, _port) = test_setup(true).await;
+
+        assert_eq!(
+            tempest.try_get_uv("ST-00000512"),
+            Err(LookupError::StationNotFound)
+        );
+        assert_eq!(
+            tempest.try_get_air_temperature("ST-00000512"),
+            Err(LookupError::StationNotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn try_get_missing_value() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        // air observations do not report UV
+        let payload = get_air_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.try_get_uv("ST-00000512"),
+            Err(LookupError::FieldUnavailable)
+        );
+        assert_eq!(tempest.try_get_air_temperature("ST-00000512"), Ok(10.0));
+    }
+
+    #[tokio::test]
+    async fn duplicate_radio_networks() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_hub_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // no collisions with a single hub
+        assert!(tempest.duplicate_radio_networks().is_empty());
+
+        // second hub on the same radio network
+        let payload = get_hub_payload_from("HB-00013031", 1495724691, [2, 1, 0, 3, 2839]);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let network_ids = tempest.radio_network_ids();
+        assert_eq!(network_ids.len(), 2);
+        assert_eq!(network_ids.get("HB-00013031"), Some(&2839));
+
+        assert_eq!(tempest.duplicate_radio_networks(), vec![2839]);
+    }
+
+    #[tokio::test]
+    async fn get_potential_temperature() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let theta = tempest
+            .get_potential_temperature("ST-00000512")
+            .expect("Unable to retrieve potential temperature");
+        assert!((theta - 20.90).abs() < 0.01);
+    }
+
+    #[tokio::test]
+    async fn get_hub_from_rain_station() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_rain_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_hub_payload_from("HB-00000001", 1495724691, [2, 1, 0, 3, 2839]);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let station = tempest
+            .get_station_by_sn("ST-00000512")
+            .expect("Unable to retrieve station");
+
+        let hub = tempest
+            .get_hub_from_station(station)
+            .expect("Unable to retrieve hub");
+
+        assert_eq!(hub.serial_number, "HB-00000001");
+    }
+
+    #[tokio::test]
+    async fn skip_caching_invalid_observation() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        // JSON has no NaN literal, an out of range f32 reading overflows to infinity instead
+        let mut observation = STATION_OBSERVATION;
+        observation[7] = 1e39;
+
+        let payload = get_station_observation_payload_from("ST-00000512", observation);
+        mock.send(payload.clone(), port);
+
+        // invalid events are still forwarded by default
+        let event = receiver.recv().await;
+        assert!(matches!(event, Some(EventType::Observation(_))));
+
+        assert_eq!(tempest.get_air_temperature("ST-00000512"), None);
+        assert_eq!(tempest.station_count(), 0);
+        assert_eq!(tempest.rejected_event_count(), 1);
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_air_temperature("ST-00000512"), Some(22.37));
+        assert_eq!(tempest.rejected_event_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn drop_invalid_events() {
+        let (mock, tempest, mut receiver, port) =
+            builder_setup(Tempest::builder().cache(true).drop_invalid(true)).await;
+
+        let mut observation = STATION_OBSERVATION;
+        observation[8] = 150.0;
+
+        let payload = get_station_observation_payload_from("ST-00000512", observation);
+        mock.send(payload.clone(), port);
+
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+
+        let event = receiver.recv().await;
+        assert!(matches!(event, Some(EventType::RapidWind(_))));
+        assert_eq!(tempest.rejected_event_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn on_event() {
+        let (mock, tempest, mut receiver, port) = test_setup(false).await;
+
+        let events = Arc::new(AtomicUsize::new(0));
+        let rapid_winds = Arc::new(AtomicUsize::new(0));
+
+        let counter = events.clone();
+        tempest.on_event(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let counter = rapid_winds.clone();
+        tempest.on_event(move |event| {
+            if matches!(event, EventType::RapidWind(_)) {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        for payload in [
+            get_rapidwind_payload(),
+            get_lightning_payload(),
+            get_rapidwind_payload(),
+        ] {
+            mock.send(payload, port);
+            receiver.recv().await;
+        }
+
+        assert_eq!(events.load(Ordering::SeqCst), 3);
+        assert_eq!(rapid_winds.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn metrics() {
+        let (mock, tempest, mut receiver, port) = test_setup(false).await;
+
+        assert_eq!(tempest.metrics(), Metrics::default());
+
+        mock.send(b"not json".to_vec(), port);
+        mock.send(
+            serde_json::to_vec(
+                &serde_json::json!({"serial_number": "ST-00000512", "type": "evt_future"}),
+            )
+            .expect("Failed to convert JSON to vector"),
+            port,
+        );
+        receiver.recv().await;
+
+        for payload in [
+            get_rapidwind_payload(),
+            get_lightning_payload(),
+            get_station_observation_payload(),
+            get_rapidwind_payload(),
+        ] {
+            mock.send(payload, port);
+            receiver.recv().await;
+        }
+
+        let metrics = tempest.metrics();
+        assert_eq!(metrics.packets_received, 6);
+        assert_eq!(metrics.parse_errors, 1);
+        assert_eq!(metrics.unknown_events, 1);
+        assert_eq!(
+            metrics.events,
+            HashMap::from([
+                ("rapid_wind".to_string(), 2),
+                ("evt_strike".to_string(), 1),
+                ("obs_st".to_string(), 1),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_event() {
+        let (mock, _tempest, mut receiver, port) = test_setup(false).await;
+
+        let json = serde_json::json!({
+            "serial_number": "st-00000512",
+            "type": "obs_future",
+            "hub_sn": "HB-00000001",
+            "obs": [[1493322445, 7]]
+        });
+        mock.send(
+            serde_json::to_vec(&json).expect("Failed to convert JSON to vector"),
+            port,
+        );
+
+        match receiver.recv().await {
+            Some(
+                ref event @ EventType::Unknown {
+                    ref event_type,
+                    ref raw,
+                },
+            ) => {
+                assert_eq!(event_type, "obs_future");
+                assert_eq!(raw, &json);
+                assert_eq!(event.get_serial_number(), "ST-00000512");
+                assert_eq!(event.get_hub_sn(), "HB-00000001");
+                assert_eq!(
+                    serde_json::to_value(event).expect("Unable to serialize event"),
+                    json
+                );
+            }
+            other => panic!("Expected an unknown event, received {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_event_handler() {
+        let (handler_tx, mut handler_rx) = mpsc::unbounded_channel();
+
+        let (mock, _tempest, _receiver, port) = builder_setup(Tempest::builder().unknown_handler(
+            Arc::new(move |event_type: &str, json: &Value| {
+                let _ = handler_tx.send((event_type.to_string(), json.clone()));
+            }),
+        ))
+        .await;
+
+        let payload = serde_json::to_vec(&serde_json::json!({
+            "serial_number": "ST-00000512",
+            "type": "evt_future",
+            "hub_sn": "HB-00000001",
+            "evt": [1493322445, 7]
+        }))
+        .expect("Failed to convert JSON to vector");
+        mock.send(payload, port);
+
+        let (event_type, json) = handler_rx
+            .recv()
+            .await
+            .expect("Unknown event handler was not called");
+
+        assert_eq!(event_type, "evt_future");
+        assert_eq!(json["serial_number"], "ST-00000512");
+        assert_eq!(json["evt"][1], 7);
+    }
+
+    #[tokio::test]
+    async fn lightning_energy_total() {
+        let clock = TestClock::new(UNIX_EPOCH + Duration::from_secs(1493322400));
+
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .clock(Arc::new(clock.clone())),
+        )
+        .await;
+
+        for strike in [
+            [1493322000, 12, 100],
+            [1493322300, 8, 200],
+            [1493322400, 5, 300],
+        ] {
+            let payload = get_lightning_payload_from("ST-00000512", strike);
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+        }
+
+        assert_eq!(
+            tempest.lightning_energy_total("ST-00000512", Duration::from_secs(200)),
+            Some(500)
+        );
+        assert_eq!(
+            tempest.lightning_energy_total("ST-00000512", Duration::from_secs(3600)),
+            Some(600)
+        );
+
+        // the window ends at the current time rather than the most recent strike
+        clock.advance(Duration::from_secs(150));
+        assert_eq!(
+            tempest.lightning_energy_total("ST-00000512", Duration::from_secs(200)),
+            Some(300)
+        );
+
+        clock.advance(Duration::from_secs(3600));
+        assert_eq!(
+            tempest.lightning_energy_total("ST-00000512", Duration::from_secs(200)),
+            Some(0)
+        );
+        assert_eq!(
+            tempest.lightning_energy_total("ST-00000513", Duration::from_secs(3600)),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn lightning_flash_rate() {
+        let clock = TestClock::new(UNIX_EPOCH + Duration::from_secs(1493322059));
+
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .clock(Arc::new(clock.clone())),
+        )
+        .await;
+
+        for strike in [
+            [1493321000, 20, 100],
+            [1493322000, 12, 100],
+            [1493322015, 10, 100],
+            [1493322030, 9, 100],
+            [1493322045, 8, 100],
+            [1493322059, 7, 100],
+        ] {
+            let payload = get_lightning_payload_from("ST-00000512", strike);
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+        }
+
+        assert_eq!(
+            tempest.lightning_flash_rate("ST-00000512", Duration::from_secs(60)),
+            Some(5.0)
+        );
+        assert_eq!(
+            tempest.lightning_flash_rate("ST-00000512", Duration::from_secs(120)),
+            Some(2.5)
+        );
+        assert_eq!(
+            tempest.lightning_flash_rate("ST-00000512", Duration::ZERO),
+            None
+        );
+
+        // no strikes since the storm passed
+        clock.advance(Duration::from_secs(3600));
+        assert_eq!(
+            tempest.lightning_flash_rate("ST-00000512", Duration::from_secs(60)),
+            Some(0.0)
+        );
+        assert_eq!(
+            tempest.lightning_flash_rate("ST-00000513", Duration::from_secs(60)),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn listen_udp_on() {
+        let mock = MockSender::bind();
+
+        let (tempest, mut receiver) =
+            Tempest::listen_udp_on(Some(Ipv4Addr::LOCALHOST.into()), Some(0), true, None)
+                .await
+                .expect("Unable to bind listener");
+
+        let port: u16 = tempest
+            .recv
+            .local_addr()
+            .expect("Unable to retrieve local address of listener")
+            .port();
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_air_temperature("ST-00000512"), Some(22.37));
+    }
+
+    #[tokio::test]
+    async fn broadcast_subscribers() {
+        let (mock, tempest, _receiver, port) = test_setup(false).await;
+
+        let mut first = tempest.subscribe();
+        let mut second = tempest.subscribe();
+
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+
+        for subscriber in [&mut first, &mut second] {
+            let event = subscriber
+                .recv()
+                .await
+                .expect("Unable to receive broadcast event");
+
+            match event {
+                EventType::RapidWind(event) => {
+                    assert_eq!(event.get_serial_number(), "ST-00000512")
+                }
+                _ => panic!("Unexpected event type received"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn bind_port_in_use() {
+        let (tempest, _receiver) =
+            Tempest::listen_udp_on(Some(Ipv4Addr::LOCALHOST.into()), Some(0), false, None)
+                .await
+                .expect("Unable to bind listener");
+
+        let port: u16 = tempest
+            .recv
+            .local_addr()
+            .expect("Unable to retrieve local address of listener")
+            .port();
+
+        let second =
+            Tempest::listen_udp_on(Some(Ipv4Addr::LOCALHOST.into()), Some(port), false, None).await;
+
+        assert!(second.is_err());
+    }
+
+    #[tokio::test]
+    async fn frost_risk() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.frost_risk("ST-00000512"), Some(FrostRisk::None));
+        assert_eq!(tempest.frost_risk("ST-00000513"), None);
+    }
+
+    #[tokio::test]
+    async fn shutdown() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+        assert!(receiver.recv().await.is_some());
+
+        tempest.shutdown().await;
+
+        // the sender is dropped once the listener task has finished
+        assert!(receiver.recv().await.is_none());
+
+        // shutting down again is a no-op
+        tempest.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn shutdown_with_full_channel() {
+        let (mock, tempest, mut receiver, port) =
+            builder_setup(Tempest::builder().channel_capacity(1)).await;
+
+        // the first event fills the channel and the listener waits to send the second
+        for _ in 0..3 {
+            mock.send(get_rapidwind_payload(), port);
+        }
+
+        while tempest.metrics().packets_received < 2 {
+            time::sleep(Duration::from_millis(10)).await;
+        }
+
+        time::timeout(Duration::from_secs(1), tempest.shutdown())
+            .await
+            .expect("Shutdown blocked by the full channel");
+
+        // the queued event is still delivered before the receiver closes
+        assert!(receiver.recv().await.is_some());
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn stale_hubs_with_test_clock() {
+        // one minute after the hub status was sent
+        let clock = TestClock::new(UNIX_EPOCH + Duration::from_secs(1495724751));
+
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .clock(Arc::new(clock.clone())),
+        )
+        .await;
+
+        let payload = get_hub_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert!(tempest.stale_hubs(Duration::from_secs(3600)).is_empty());
+
+        clock.advance(Duration::from_secs(7200));
+
+        assert_eq!(
+            tempest.stale_hubs(Duration::from_secs(3600)),
+            vec!["HB-00013030".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_interval_drift_with_test_clock() {
+        let clock = TestClock::new(UNIX_EPOCH + Duration::from_secs(1588948614));
+
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .clock(Arc::new(clock.clone())),
+        )
+        .await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // a single observation has no interval to compare against
+        assert_eq!(tempest.report_interval_drift("ST-00000512"), None);
+
+        // observation arrives two minutes later for a one minute report interval
+        clock.advance(Duration::from_secs(120));
+
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.report_interval_drift("ST-00000512"), Some(2.0));
+    }
+
+    #[tokio::test]
+    async fn last_raw_json() {
+        let (mock, tempest, mut receiver, port) =
+            builder_setup(Tempest::builder().store_raw_json(true)).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.last_raw_json("ST-00000512"),
+            Some(String::from_utf8(payload).expect("Payload is not valid UTF-8"))
+        );
+        assert_eq!(tempest.last_raw_json("ST-00000513"), None);
+    }
+
+    #[tokio::test]
+    async fn last_raw_json_disabled() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.last_raw_json("ST-00000512"), None);
+    }
+
+    #[tokio::test]
+    async fn get_wind_cardinal() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let mut obs = STATION_OBSERVATION;
+        obs[4] = 350.0;
+        let payload = get_station_observation_payload_from("ST-00000512", obs);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_cardinal("ST-00000512"), Some("N"));
+        assert_eq!(tempest.get_wind_cardinal("ST-00000513"), None);
+    }
+
+    #[tokio::test]
+    async fn get_mixing_ratio_and_specific_humidity() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let mut observation = STATION_OBSERVATION;
+        observation[6] = 1013.0;
+        observation[7] = 20.0;
+        observation[8] = 50.0;
+
+        let payload = get_station_observation_payload_from("ST-00000512", observation);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let mixing_ratio = tempest
+            .get_mixing_ratio("ST-00000512")
+            .expect("Unable to retrieve mixing ratio");
+        let specific_humidity = tempest
+            .get_specific_humidity("ST-00000512")
+            .expect("Unable to retrieve specific humidity");
+
+        assert!((mixing_ratio - 7.245).abs() < 0.01);
+        assert!((specific_humidity - 7.193).abs() < 0.01);
+        assert_eq!(tempest.get_mixing_ratio("ST-00000513"), None);
+    }
+
+    #[tokio::test]
+    async fn clock_skew() {
+        // local clock five seconds behind the station
+        let clock = TestClock::new(UNIX_EPOCH + Duration::from_secs(1493322440));
+
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .clock(Arc::new(clock.clone())),
+        )
+        .await;
+
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.clock_skew("ST-00000512"), Some(5));
+
+        // local clock ten seconds ahead of the station
+        clock.advance(Duration::from_secs(15));
+
+        let payload = get_lightning_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.clock_skew("ST-00000512"), Some(-10));
+        assert_eq!(tempest.clock_skew("ST-00000513"), None);
+
+        // observations are read to the second, beyond the precision of an f32
+        clock.set(UNIX_EPOCH + Duration::from_secs(1588948600));
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.clock_skew("ST-00000512"), Some(14));
+
+        let payload = get_sky_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.clock_skew("ST-00000512"),
+            Some(1493321340 - 1588948600)
+        );
+    }
+
+    #[tokio::test]
+    async fn first_seen() {
+        let start = UNIX_EPOCH + Duration::from_secs(1493322440);
+        let clock = TestClock::new(start);
+
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .clock(Arc::new(clock.clone())),
+        )
+        .await;
+
+        for payload in [get_rapidwind_payload(), get_hub_payload()] {
+            mock.send(payload, port);
+            receiver.recv().await;
+        }
+
+        clock.advance(Duration::from_secs(60));
+
+        for payload in [get_lightning_payload(), get_hub_payload()] {
+            mock.send(payload, port);
+            receiver.recv().await;
+        }
+
+        assert_eq!(tempest.first_seen("ST-00000512"), Some(start));
+        assert_eq!(tempest.first_seen("HB-00013030"), Some(start));
+        assert_eq!(tempest.first_seen("ST-00000513"), None);
+
+        let station = tempest
+            .get_station_by_sn("ST-00000512")
+            .expect("Unable to retrieve station");
+        assert_eq!(station.last_seen, Some(start + Duration::from_secs(60)));
+    }
+
+    #[tokio::test]
+    async fn is_online() {
+        let start = UNIX_EPOCH + Duration::from_secs(1493322440);
+        let clock = TestClock::new(start);
+
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .clock(Arc::new(clock.clone())),
+        )
+        .await;
+
+        for payload in [get_rapidwind_payload(), get_hub_payload()] {
+            mock.send(payload, port);
+            receiver.recv().await;
+        }
+
+        assert_eq!(tempest.station_last_seen("ST-00000512"), Some(start));
+        assert_eq!(tempest.station_last_seen("ST-00000513"), None);
+
+        let window = Duration::from_secs(1);
+        assert_eq!(tempest.is_online("ST-00000512", window), Some(true));
+        assert_eq!(tempest.is_online("HB-00013030", window), Some(true));
+
+        clock.advance(Duration::from_secs(2));
+
+        assert_eq!(tempest.is_online("ST-00000512", window), Some(false));
+        assert_eq!(tempest.is_online("HB-00013030", window), Some(false));
+        assert_eq!(tempest.is_online("ST-00000513", window), None);
+
+        // a new event brings the station back online
+        mock.send(get_lightning_payload(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.station_last_seen("ST-00000512"),
+            Some(start + Duration::from_secs(2))
+        );
+        assert_eq!(tempest.is_online("ST-00000512", window), Some(true));
+    }
+
+    #[tokio::test]
+    async fn wind_history() {
+        let (mock, tempest, mut receiver, port) =
+            builder_setup(Tempest::builder().cache(true).wind_history_capacity(3)).await;
+
+        for sample in 0..5 {
+            let payload = get_rapidwind_payload_from(
+                "ST-00000512",
+                [1588948614.0 + 3.0 * sample as f64, sample as f64, 90.0],
+            );
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+        }
+
+        assert_eq!(
+            tempest.wind_history("ST-00000512"),
+            [(1588948620, 2.0), (1588948623, 3.0), (1588948626, 4.0)]
+        );
+        assert!(tempest.wind_history("ST-00000513").is_empty());
+    }
+
+    #[tokio::test]
+    async fn freshest_wind() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        // observation at 1588948614 with a 0.22 m/s average and 0.27 m/s gust
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // rapid wind ten minutes later
+        for (timestamp, speed) in [(1588949214.0, 4.0), (1588949217.0, 6.0)] {
+            let payload = get_rapidwind_payload_from("ST-00000512", [timestamp, speed, 90.0]);
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+        }
+
+        assert_eq!(tempest.get_wind_avg("ST-00000512"), Some(5.0));
+        assert_eq!(tempest.get_wind_gust("ST-00000512"), Some(6.0));
+        assert_eq!(tempest.try_get_wind_gust("ST-00000512"), Ok(6.0));
+
+        // a newer observation takes over again
+        let mut obs = STATION_OBSERVATION;
+        obs[0] = 1588949814.0;
+        obs[2] = 1.5;
+        obs[3] = 2.5;
+        let payload = get_station_observation_payload_from("ST-00000512", obs);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_wind_avg("ST-00000512"), Some(1.5));
+        assert_eq!(tempest.get_wind_gust("ST-00000512"), Some(2.5));
+        assert_eq!(tempest.get_wind_gust("ST-00000513"), None);
+    }
+
+    #[tokio::test]
+    async fn get_dew_point() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        // rapid wind events carry no humidity
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(tempest.get_dew_point("ST-00000512"), None);
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let dew_point = tempest
+            .get_dew_point("ST-00000512")
+            .expect("Unable to retrieve dew point");
+        assert!((dew_point - 11.5).abs() < 0.1);
+    }
+
+    #[tokio::test]
+    async fn hub_aggregate() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let mut observation = STATION_OBSERVATION;
+        observation[7] = 20.0;
+        let payload = get_station_observation_payload_from("ST-00000513", observation);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        // a station under another hub
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let aggregate = tempest
+            .hub_aggregate("HB-00013030")
+            .expect("Unable to aggregate hub");
+
+        assert_eq!(aggregate.station_count, 2);
+
+        let temperature = aggregate
+            .air_temperature
+            .expect("Unable to retrieve aggregated temperature");
+        assert!((temperature.average - 21.185).abs() < 0.001);
+        assert_eq!(temperature.min, 20.0);
+        assert_eq!(temperature.max, 22.37);
+
+        assert_eq!(tempest.hub_aggregate("HB-00000002"), None);
+    }
+
+    #[tokio::test]
+    async fn receiver_stream() {
+        use tokio_stream::StreamExt;
+
+        let (mock, _tempest, receiver, port) = test_setup(false).await;
+
+        mock.send(get_rain_payload(), port);
+        mock.send(get_rapidwind_payload(), port);
+
+        let mut stream = ReceiverStream::new(receiver)
+            .filter(|event| matches!(event, EventType::RapidWind(_)))
+            .take(1);
+
+        match stream.next().await {
+            Some(EventType::RapidWind(event)) => {
+                assert_eq!(event.get_serial_number(), "ST-00000512")
+            }
+            _ => panic!("Unexpected event type received"),
+        }
+
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn station_and_hub_serials() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        assert!(tempest.station_serials().is_empty());
+        assert!(tempest.hub_serials().is_empty());
+
+        let payload = get_station_observation_payload_from("ST-00000513", STATION_OBSERVATION);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_hub_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        assert_eq!(
+            tempest.station_serials(),
+            vec!["ST-00000512", "ST-00000513"]
+        );
+        assert_eq!(tempest.hub_serials(), vec!["HB-00013030"]);
+    }
+
+    #[tokio::test]
+    async fn cache_updates_coalesced() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let mut updates = tempest.cache_updates();
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let serial_number = time::timeout(Duration::from_secs(1), updates.recv())
+            .await
+            .expect("Timed out waiting for cache update");
+        assert_eq!(serial_number.as_deref(), Some("ST-00000512"));
+
+        // the second observation was coalesced with the first
+        assert!(
+            time::timeout(CACHE_UPDATE_DEBOUNCE * 2, updates.recv())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn all_stations_and_hubs() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-    /// Retrieve the most recent illuminance (lux) of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_lux(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.illuminance)?
-    }
+        let payload = get_station_observation_payload_from("ST-00000513", STATION_OBSERVATION);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-    /// Retrieve the most recent UV Index of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_uv(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.uv)?
-    }
+        let payload = get_hub_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-    /// Retrieve the most recent solar radiation (W/m^2) of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_solar_radiation(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.solar_radiation)?
-    }
+        let stations = tempest.all_stations();
+        assert_eq!(stations.len(), tempest.station_count());
+        assert_eq!(stations.len(), 2);
 
-    /// Retrieve the most recent measurement of rain (mm) in the previous minute of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_rain_prev_min(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.rain_amount_prev_minute)?
+        let hubs = tempest.all_hubs();
+        assert_eq!(hubs.len(), tempest.hub_count());
+        assert_eq!(hubs[0].serial_number, "HB-00013030");
     }
 
-    /// Retrieve the timestamp of the previous rain start from a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_prev_rain_start(&self, serial_number: &str) -> Option<u64> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.prev_rain_timestamp)?
-    }
+    #[tokio::test]
+    async fn sparkline() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-    /// Retrieve the most recent precipitation type of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_precipitation_type(&self, serial_number: &str) -> Option<PrecipitationType> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.precipitation_type)?
-    }
+        for temperature in [20.0, 21.0, 22.0, 23.0, 24.0] {
+            let mut obs = STATION_OBSERVATION;
+            obs[7] = temperature;
+            let payload = get_station_observation_payload_from("ST-00000512", obs);
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+        }
 
-    /// Retrieve the most recent measurement of lightning strike average distance (km) of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_lightning_avg_distance(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.lightning_strike_avg_distance)?
-    }
+        assert_eq!(
+            tempest.sparkline("ST-00000512", SampledMetric::AirTemperature, 3),
+            Some(vec![22.0, 23.0, 24.0])
+        );
 
-    /// Retrieve the most recent lightning strike count of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_lightning_count(&self, serial_number: &str) -> Option<f32> {
-        self.get_station_by_sn(serial_number)
-            .map(|station| station.lightning_strike_count)?
-    }
+        assert_eq!(
+            tempest.sparkline("ST-00000512", SampledMetric::AirTemperature, 7),
+            Some(vec![20.0, 20.0, 20.0, 21.0, 22.0, 23.0, 24.0])
+        );
 
-    /// Retrieve the most recent lightning strike timestamp of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_lightning_timestamp(&self, serial_number: &str) -> Option<u64> {
-        Some(
-            self.get_station_by_sn(serial_number)?
-                .lightning_event?
-                .get_timestamp(),
-        )
+        assert_eq!(
+            tempest.sparkline("ST-00000513", SampledMetric::AirTemperature, 3),
+            None
+        );
     }
 
-    /// Retrieve the most recent lightning strike distance (km, kilometers) of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_lightning_distance(&self, serial_number: &str) -> Option<u64> {
-        Some(
-            self.get_station_by_sn(serial_number)?
-                .lightning_event?
-                .get_strike_distance(),
-        )
-    }
+    #[tokio::test]
+    async fn evict_stale_with_test_clock() {
+        let clock = TestClock::new(UNIX_EPOCH + Duration::from_secs(1588948614));
 
-    /// Retrieve the most recent lightning strike energy (J, joules) of a cached station based on the provided station's serial number
-    ///
-    /// Returns the value as a Some(..) if present otherwise returns a None
-    pub fn get_lightning_energy(&self, serial_number: &str) -> Option<u64> {
-        Some(
-            self.get_station_by_sn(serial_number)?
-                .lightning_event?
-                .get_strike_energy(),
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .clock(Arc::new(clock.clone())),
         )
-    }
+        .await;
 
-    /// Listen to UDP packets sent from the WeatherFlow Tempest hub
-    ///
-    /// Returns a Tokio receiver containing a weather event as an `EventType`.
-    /// The `Tempest` instance is disregarded in this use case.
-    pub async fn listen_udp() -> Receiver<EventType> {
-        let (_, rx) = Tempest::listen_udp_internal(None, None, false, None).await;
-        rx
-    }
+        let payload = get_station_observation_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-    /// Listen to UDP packets sent from the WeatherFlow Tempest hub and cache data about hubs and stations reporting events
-    ///
-    /// Returns a `Tempest` instance along with a Tokio receiver containining a weather event as an `EventType`
-    pub async fn listen_udp_with_cache() -> (Tempest, Receiver<EventType>) {
-        Tempest::listen_udp_internal(None, None, true, None).await
-    }
+        clock.advance(Duration::from_secs(600));
 
-    /// Listen to UDP packets sent from the WeatherFlow Tempest hub and only share events that match the provided serial number.
-    ///
-    /// Returns a Tokio receiver accepting weather events as an `EventType`.
-    /// The `Tempest` instance is disregarded in this use case.
-    pub async fn listen_udp_subscribe(station_filter: Vec<&str>) -> Receiver<EventType> {
-        let station_filter = station_filter
-            .iter()
-            .map(|&station| station.to_string())
-            .collect();
+        let payload = get_station_observation_payload_from("ST-00000513", STATION_OBSERVATION);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-        let (_, rx) = Tempest::listen_udp_internal(None, None, false, Some(station_filter)).await;
-        rx
+        assert_eq!(tempest.evict_stale(Duration::from_secs(900)), 0);
+
+        clock.advance(Duration::from_secs(600));
+
+        // the station was last heard from ten minutes before the other
+        assert_eq!(tempest.evict_stale(Duration::from_secs(900)), 1);
+        assert_eq!(tempest.station_serials(), vec!["ST-00000513"]);
+
+        assert_eq!(tempest.evict_stale(Duration::ZERO), 1);
+        assert_eq!(tempest.station_count(), 0);
     }
 
-    /// Internal function used for parsing UDP packets containing JSON weather data.
-    ///
-    /// When a weather event is received, a few things can happen depending on the parameters passed into this function.
-    ///
-    /// If `caching` is set to true then the weather event will be saved to cache.
-    ///
-    /// If `station_filter` is Some(..) and contains station serial numbers then it will only send the weather event
-    /// back over the mpsc channel if the weather event's serial number matches the provided serial number.
-    /// This acts like a form of filtering.
-    ///
-    /// This function returns both an instance of `Tempest` for further weather data retrieval (air temperature, wind, etc)
-    /// and `rx` is an mpsc receiver for accepting weather event data as it arrives.
-    async fn listen_udp_internal(
-        address: Option<Ipv4Addr>,
-        port: Option<u16>,
-        caching: bool,
-        station_filter: Option<Vec<String>>,
-    ) -> (Tempest, Receiver<EventType>) {
-        let mut tempest = Tempest::bind(address, port).await;
-        let (tx, rx) = mpsc::channel(16);
+    #[tokio::test]
+    async fn wind_sensor_stuck() {
+        let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let tempest_clone: Tempest = tempest.clone();
+        assert_eq!(tempest.wind_sensor_stuck("ST-00000512", 5), None);
 
-        tokio::spawn(async move {
-            loop {
-                let mut recv_buffer: Vec<u8> = vec![0; DEFAULT_BUFFER_SIZE];
+        for _ in 0..4 {
+            let payload = get_rapidwind_payload();
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+        }
 
-                // receive udp packet into buffer
-                let len = match tempest.recv.recv_from(&mut recv_buffer).await {
-                    Ok((len, _addr)) => len,
-                    Err(e) => {
-                        eprintln!("Failed to receive UDP packet: {e}");
-                        continue;
-                    }
-                };
+        assert_eq!(tempest.wind_sensor_stuck("ST-00000512", 5), Some(false));
 
-                // deserialize buffer contents into json value
-                let json: Value = match serde_json::from_slice(&recv_buffer[0..len]) {
-                    Ok(value) => value,
-                    Err(e) => {
-                        eprintln!(
-                            "Failed to deserialize packet contents into serde JSON value: {e}"
-                        );
-                        continue;
-                    }
-                };
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-                match json["type"].as_str() {
-                    // Station observation event
-                    Some("obs_st") => {
-                        let evt: Result<ObservationEvent, Error> = serde_json::from_value(json);
-
-                        match evt {
-                            Ok(event) => {
-                                if caching {
-                                    tempest.cache_station_observation(event.clone());
-                                }
-
-                                // send event if no serial number provided or on a match
-                                if station_filter.clone().is_none_or(|stations| {
-                                    stations.contains(&event.get_serial_number())
-                                }) {
-                                    let _ = tx
-                                        .send(EventType::Observation(event))
-                                        .await
-                                        .inspect_err(|e| eprintln!("Unable to send {e:?}"));
-                                }
-                            }
-                            Err(e) => eprintln!("Error : {e}"),
-                        }
-                    }
-                    // Air observation event
-                    Some("obs_air") => {
-                        let evt: Result<ObservationAirEvent, Error> = serde_json::from_value(json);
-
-                        match evt {
-                            Ok(event) => {
-                                if caching {
-                                    tempest.cache_station_air_event(event.clone());
-                                }
-
-                                // send event if no serial number provided or on a match
-                                if station_filter.clone().is_none_or(|stations| {
-                                    stations.contains(&event.get_serial_number())
-                                }) {
-                                    let _ = tx.send(EventType::Air(event)).await.inspect_err(|e| {
-                                        eprintln!("Unable to send {e:?}");
-                                    });
-                                }
-                            }
-                            Err(e) => eprintln!("Error : {e}"),
-                        }
-                    }
-                    // Sky observation event
-                    Some("obs_sky") => {
-                        println!("Converting JSON to serde value");
-                        let evt: Result<ObservationSkyEvent, Error> = serde_json::from_value(json);
-
-                        println!("Converted");
-
-                        match evt {
-                            Ok(event) => {
-                                if caching {
-                                    println!("Caching");
-                                    tempest.cache_station_sky_event(event.clone());
-                                }
-
-                                // send event if no serial number provided or on a match
-                                if station_filter.clone().is_none_or(|stations| {
-                                    stations.contains(&event.get_serial_number())
-                                }) {
-                                    let _ = tx.send(EventType::Sky(event)).await.inspect_err(|e| {
-                                        eprintln!("Unable to send {e:?}");
-                                    });
-                                }
-                            }
-                            Err(e) => eprintln!("Error: {e}"),
-                        }
-                    }
-                    // Hub Status Event
-                    Some("hub_status") => {
-                        let evt: Result<HubStatusEvent, Error> = serde_json::from_value(json);
-
-                        match evt {
-                            Ok(event) => {
-                                if caching {
-                                    tempest.hub_upsert(Hub::from(event.clone()));
-                                }
-
-                                // send event if no serial number provided or on a match
-                                if station_filter.clone().is_none_or(|stations| {
-                                    stations.contains(&event.get_serial_number())
-                                }) {
-                                    let _ = tx
-                                        .send(EventType::HubStatus(event))
-                                        .await
-                                        .inspect_err(|e| eprintln!("Unable to send {e:?}"));
-                                }
-                            }
-                            Err(e) => eprintln!("Error : {e}"),
-                        }
-                    }
-                    //  Rapid wind event
-                    Some("rapid_wind") => {
-                        let evt: Result<RapidWindEvent, Error> = serde_json::from_value(json);
-
-                        match evt {
-                            Ok(event) => {
-                                if caching {
-                                    tempest.cache_station_wind_event(event.clone());
-                                }
-
-                                // send event if no serial number provided or on a match
-                                if station_filter.clone().is_none_or(|stations| {
-                                    stations.contains(&event.get_serial_number())
-                                }) {
-                                    let _ = tx.send(EventType::RapidWind(event)).await.inspect_err(
-                                        |e| {
-                                            eprintln!("Unable to send {e:?}");
-                                        },
-                                    );
-                                }
-                            }
-                            Err(e) => eprintln!("Error : {e}"),
-                        }
-                    }
-                    // Precipitation event
-                    Some("evt_precip") => {
-                        let evt: Result<RainStartEvent, Error> = serde_json::from_value(json);
-
-                        match evt {
-                            Ok(event) => {
-                                if caching {
-                                    tempest.cache_station_rain_event(event.clone());
-                                }
-
-                                // send event if no serial number provided or on a match
-                                if station_filter.clone().is_none_or(|stations| {
-                                    stations.contains(&event.get_serial_number())
-                                }) {
-                                    let _ =
-                                        tx.send(EventType::Rain(event)).await.inspect_err(|e| {
-                                            eprintln!("Unable to send {e:?}");
-                                        });
-                                }
-                            }
-                            Err(e) => eprintln!("Error : {e}"),
-                        }
-                    }
-                    // Lightning strike event
-                    Some("evt_strike") => {
-                        let evt: Result<LightningStrikeEvent, Error> = serde_json::from_value(json);
-
-                        match evt {
-                            Ok(event) => {
-                                if caching {
-                                    tempest.cache_station_lightning_event(event.clone());
-                                }
-
-                                // send event if no serial number provided or on a match
-                                if station_filter.clone().is_none_or(|stations| {
-                                    stations.contains(&event.get_serial_number())
-                                }) {
-                                    let _ = tx.send(EventType::Lightning(event)).await.inspect_err(
-                                        |e| {
-                                            eprintln!("Unable to send {e:?}");
-                                        },
-                                    );
-                                }
-                            }
-                            Err(e) => eprintln!("Error : {e}"),
-                        }
-                    }
-                    // Device status event
-                    Some("device_status") => {
-                        let evt: Result<DeviceStatusEvent, Error> = serde_json::from_value(json);
-
-                        match evt {
-                            Ok(event) => {
-                                if caching {
-                                    tempest.cache_station_device_status(event.clone());
-                                }
-
-                                // send event if no serial number provided or on a match
-                                if station_filter.clone().is_none_or(|stations| {
-                                    stations.contains(&event.get_serial_number())
-                                }) {
-                                    let _ = tx
-                                        .send(EventType::DeviceStatus(event))
-                                        .await
-                                        .inspect_err(|e| {
-                                            eprintln!("Unable to send {e:?}");
-                                        });
-                                }
-                            }
-                            Err(e) => eprintln!("Error : {e}"),
-                        }
-                    }
-                    _ => {
-                        eprintln!("Unknown event type received");
-                    }
-                };
-            }
-        });
+        assert_eq!(tempest.wind_sensor_stuck("ST-00000512", 5), Some(true));
 
-        (tempest_clone, rx)
-    }
-}
+        // a changing reading resets the run
+        let payload = get_rapidwind_payload_from("ST-00000512", [1493322448.0, 2.4, 128.0]);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::mock::MockSender;
-    use crate::test_common::*;
+        assert_eq!(tempest.wind_sensor_stuck("ST-00000512", 5), Some(false));
+        assert_eq!(tempest.wind_sensor_stuck("ST-00000512", 1), Some(true));
+    }
 
-    async fn test_setup(caching: bool) -> (MockSender, Tempest, Receiver<EventType>, u16) {
+    #[tokio::test]
+    async fn multicast_loopback() {
+        let group = Ipv4Addr::new(239, 255, 50, 222);
         let mock = MockSender::bind();
 
-        let (tempest, receiver) =
-            Tempest::listen_udp_internal(Some(Ipv4Addr::new(127, 0, 0, 1)), Some(0), caching, None)
-                .await;
+        let (tempest, mut receiver) = Tempest::builder()
+            .port(0)
+            .multicast(group, Ipv4Addr::LOCALHOST)
+            .listen()
+            .await
+            .expect("Unable to bind listener");
 
         let port: u16 = tempest
             .recv
@@ -840,406 +4529,781 @@ mod test {
             .expect("Unable to retrieve local address of listener")
             .port();
 
-        (mock, tempest, receiver, port)
+        mock.send_to(get_rapidwind_payload(), group.into(), port);
+
+        match receiver.recv().await {
+            Some(EventType::RapidWind(event)) => {
+                assert_eq!(event.get_serial_number(), "ST-00000512")
+            }
+            _ => panic!("Unexpected event type received"),
+        }
     }
 
     #[tokio::test]
-    async fn station_count() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn listen_udp_on_ipv6() {
+        let mock = MockSender::bind_v6();
 
-        let payload = get_station_observation_payload();
+        let (tempest, mut receiver) =
+            Tempest::listen_udp_on(Some(Ipv6Addr::LOCALHOST.into()), Some(0), true, None)
+                .await
+                .expect("Unable to bind listener");
 
-        // check cached station count while empty
-        assert_eq!(0, tempest.station_count());
+        let address = tempest
+            .recv
+            .local_addr()
+            .expect("Unable to retrieve local address of listener");
+        assert!(address.is_ipv6());
 
-        // check cached station count after receiving a station observation event
-        mock.send(payload.clone(), port);
+        mock.send(get_station_observation_payload(), address.port());
         receiver.recv().await;
-        assert_eq!(1, tempest.station_count());
 
-        // check cached station count after receiving a station observation event
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
-        assert_eq!(1, tempest.station_count());
+        assert_eq!(tempest.get_air_temperature("ST-00000512"), Some(22.37));
     }
 
     #[tokio::test]
-    async fn hub_count() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn builder_ipv6() {
+        let mock = MockSender::bind_v6();
 
-        let payload = get_hub_payload();
+        let (tempest, mut receiver) = Tempest::builder()
+            .address(Ipv6Addr::LOCALHOST)
+            .port(0)
+            .listen()
+            .await
+            .expect("Unable to bind listener");
 
-        // check cached hub count while empty
-        assert_eq!(0, tempest.hub_count());
+        let port: u16 = tempest
+            .recv
+            .local_addr()
+            .expect("Unable to retrieve local address of listener")
+            .port();
 
-        // check cached hub count after receiving a hub status event
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
-        assert_eq!(1, tempest.hub_count());
+        mock.send(get_rapidwind_payload(), port);
 
-        // check cached hub count after receiving a hub status event for the same hub
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
-        assert_eq!(1, tempest.hub_count());
+        match receiver.recv().await {
+            Some(EventType::RapidWind(event)) => {
+                assert_eq!(event.get_serial_number(), "ST-00000512")
+            }
+            _ => panic!("Unexpected event type received"),
+        }
     }
 
     #[tokio::test]
-    async fn get_hub_by_sn() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn strongest_lightning() {
+        let clock = TestClock::new(UNIX_EPOCH + Duration::from_secs(1493322400));
 
-        let payload = get_hub_payload();
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .cache(true)
+                .clock(Arc::new(clock.clone())),
+        )
+        .await;
+
+        for strike in [
+            [1493322000, 12, 900],
+            [1493322300, 8, 400],
+            [1493322400, 5, 200],
+        ] {
+            let payload = get_lightning_payload_from("ST-00000512", strike);
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+        }
 
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        let strongest = tempest
+            .strongest_lightning("ST-00000512", Duration::from_secs(3600))
+            .expect("Unable to retrieve strongest strike");
+        assert_eq!(strongest.try_get_strike_energy(), Ok(900));
+        assert_eq!(strongest.try_get_strike_distance(), Ok(12));
+
+        // the strongest strike falls outside of the window
+        let strongest = tempest
+            .strongest_lightning("ST-00000512", Duration::from_secs(200))
+            .expect("Unable to retrieve strongest strike");
+        assert_eq!(strongest.try_get_strike_energy(), Ok(400));
+
+        // strikes from a storm that has passed are no longer recent
+        clock.advance(Duration::from_secs(3600));
+        let strongest = tempest
+            .strongest_lightning("ST-00000512", Duration::from_secs(7200))
+            .expect("Unable to retrieve strongest strike");
+        assert_eq!(strongest.try_get_strike_energy(), Ok(900));
+        assert_eq!(
+            tempest.strongest_lightning("ST-00000512", Duration::from_secs(200)),
+            None
+        );
 
-        // try to retrieve hub with correct SN
-        let hub = tempest.get_hub_by_sn("HB-00013030");
+        assert_eq!(
+            tempest.strongest_lightning("ST-00000513", Duration::from_secs(3600)),
+            None
+        );
+    }
 
-        assert!(hub.is_some());
+    #[tokio::test]
+    async fn buffer_size_bounds() {
+        let (_mock, tempest, _receiver, _port) =
+            builder_setup(Tempest::builder().buffer_size(0)).await;
+        assert_eq!(tempest.read_inner().buffer_size, 1);
 
-        // try to retrieve hub with incorrect SN
-        let hub = tempest.get_hub_by_sn("HB-00000000");
+        let (_mock, tempest, _receiver, _port) =
+            builder_setup(Tempest::builder().buffer_size(100_000)).await;
+        assert_eq!(tempest.read_inner().buffer_size, 65507);
 
-        assert!(hub.is_none())
+        let (_mock, tempest, _receiver, _port) = builder_setup(Tempest::builder()).await;
+        assert_eq!(tempest.read_inner().buffer_size, 4096);
     }
 
     #[tokio::test]
-    async fn get_hub_from_station() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn truncated_packet() {
+        let (mock, tempest, mut receiver, port) =
+            builder_setup(Tempest::builder().buffer_size(128)).await;
 
-        let payload = get_hub_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        assert!(get_station_observation_payload().len() > 128);
+        assert!(get_rapidwind_payload().len() < 128);
 
         let payload = get_station_observation_payload();
         mock.send(payload.clone(), port);
-        receiver.recv().await;
 
-        let station = tempest
-            .get_station_by_sn("ST-00000512")
-            .expect("Unable to retrieve station");
+        let payload = get_rapidwind_payload();
+        mock.send(payload.clone(), port);
 
-        let hub = tempest.get_hub_from_station(station);
+        // the truncated observation fails to parse and only the rapid wind event arrives
+        match receiver.recv().await {
+            Some(EventType::RapidWind(event)) => {
+                assert_eq!(event.get_serial_number(), "ST-00000512")
+            }
+            _ => panic!("Unexpected event type received"),
+        }
 
-        assert!(hub.is_some());
+        assert_eq!(tempest.truncated_packet_count(), 1);
     }
 
     #[tokio::test]
-    async fn get_station_by_sn() {
+    async fn resampled_stream() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
         let payload = get_station_observation_payload();
         mock.send(payload.clone(), port);
         receiver.recv().await;
 
-        // try to retrieve station with correct SN
-        let station = tempest.get_station_by_sn("ST-00000512");
+        let mut resampled = tempest.resampled_stream(
+            "ST-00000512",
+            SampledMetric::AirTemperature,
+            Duration::from_millis(20),
+        );
 
-        assert!(station.is_some());
+        // the cached value is held without new packets arriving
+        for _ in 0..3 {
+            assert_eq!(resampled.recv().await, Some(22.37));
+        }
 
-        // try to retrieve hub with incorrect SN
-        let station = tempest.get_station_by_sn("ST-00000513");
+        let mut obs = STATION_OBSERVATION;
+        obs[7] = 25.0;
+        let payload = get_station_observation_payload_from("ST-00000512", obs);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-        assert!(station.is_none())
+        // skip samples taken before the update
+        let mut value = resampled.recv().await;
+        while value == Some(22.37) {
+            value = resampled.recv().await;
+        }
+        assert_eq!(value, Some(25.0));
     }
 
     #[tokio::test]
-    async fn get_stations_by_hub_sn() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn precip_transitions() {
+        let clock = TestClock::new(UNIX_EPOCH + Duration::from_secs(1588948614));
 
-        // cache hub
-        let payload = get_hub_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        let (mock, tempest, mut receiver, port) =
+            builder_setup(Tempest::builder().clock(Arc::new(clock.clone()))).await;
 
-        // cache station 1
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        let mut transitions = tempest.precip_transitions();
 
-        // cache station 2
-        let payload = get_secondary_station_observation_payload();
+        let mut clear = STATION_OBSERVATION;
+        clear[13] = 0.0;
+        let mut rain = STATION_OBSERVATION;
+        rain[13] = 1.0;
+
+        for obs in [clear, rain] {
+            let payload = get_station_observation_payload_from("ST-00000512", obs);
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+        }
+
+        assert_eq!(
+            transitions.try_recv(),
+            Ok(PrecipTransition::Started {
+                serial_number: "ST-00000512".to_string()
+            })
+        );
+
+        // a brief gap is not reported
+        clock.advance(Duration::from_secs(30));
+        let payload = get_station_observation_payload_from("ST-00000512", clear);
         mock.send(payload.clone(), port);
         receiver.recv().await;
 
-        let stations = tempest.get_stations_by_hub_sn("HB-00013030");
+        assert!(transitions.try_recv().is_err());
 
-        assert_eq!(stations.len(), 2);
-    }
+        clock.advance(Duration::from_secs(30));
+        let payload = get_station_observation_payload_from("ST-00000512", clear);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-    #[tokio::test]
-    async fn cache_rain_event_only() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+        assert_eq!(
+            transitions.try_recv(),
+            Ok(PrecipTransition::Stopped {
+                serial_number: "ST-00000512".to_string()
+            })
+        );
 
+        // a rain start event starts precipitation again
         let payload = get_rain_payload();
         mock.send(payload.clone(), port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_prev_rain_start("ST-00000512"), Some(1493322445));
+        assert!(matches!(
+            transitions.try_recv(),
+            Ok(PrecipTransition::Started { .. })
+        ));
     }
 
     #[tokio::test]
-    async fn cache_air_event_only() {
+    async fn get_par() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let payload = get_air_payload();
+        assert_eq!(tempest.get_par("ST-00000512"), None);
+
+        let payload = get_sky_payload();
         mock.send(payload.clone(), port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_air_temperature("ST-00000512"), Some(10.0));
+        assert_eq!(tempest.get_par("ST-00000512"), Some(262.6));
     }
 
-    #[tokio::test]
-    async fn cache_sky_event_only() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    /// Send rapid wind events with consecutive timestamps, waiting until the listener has shared `shared` of them
+    async fn send_stalled(
+        mock: &MockSender,
+        tempest: &Tempest,
+        port: u16,
+        count: u64,
+        shared: u64,
+    ) -> broadcast::Receiver<EventType> {
+        let mut subscriber = tempest.subscribe();
+
+        for offset in 0..count {
+            let ob = [1493322445.0 + offset as f64, 2.3, 128.0];
+            mock.send(get_rapidwind_payload_from("ST-00000512", ob), port);
+        }
 
-        let payload = get_sky_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        for _ in 0..shared {
+            subscriber
+                .recv()
+                .await
+                .expect("Unable to receive shared event");
+        }
 
-        println!("Assert");
-        assert_eq!(tempest.get_lux("ST-00000512"), Some(9000.0));
+        subscriber
     }
 
-    #[tokio::test]
-    async fn cache_wind_event_only() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    /// Receive the timestamps of the rapid wind events queued for the channel receiver
+    async fn queued_timestamps(receiver: &mut Receiver<EventType>) -> Vec<u64> {
+        let mut timestamps = Vec::new();
 
-        let payload = get_rapidwind_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        while let Ok(Some(event)) = time::timeout(Duration::from_millis(100), receiver.recv()).await
+        {
+            if let EventType::RapidWind(event) = event {
+                timestamps.push(event.get_timestamp_checked().unwrap_or_default() - 1493322445);
+            }
+        }
 
-        assert_eq!(tempest.get_wind_speed("ST-00000512"), Some(2.3));
+        timestamps
     }
 
     #[tokio::test]
-    async fn cache_lightning_event_only() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn overflow_drop_newest() {
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .channel_capacity(2)
+                .overflow_policy(OverflowPolicy::DropNewest),
+        )
+        .await;
 
-        let payload = get_lightning_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        send_stalled(&mock, &tempest, port, 5, 5).await;
 
-        assert_eq!(tempest.get_lightning_energy("ST-00000512"), Some(3848));
+        assert_eq!(queued_timestamps(&mut receiver).await, vec![0, 1]);
+        assert_eq!(tempest.dropped_event_count(), 3);
     }
 
     #[tokio::test]
-    async fn get_battery_voltage() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn overflow_drop_oldest() {
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .channel_capacity(2)
+                .overflow_policy(OverflowPolicy::DropOldest),
+        )
+        .await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        send_stalled(&mock, &tempest, port, 6, 6).await;
 
-        assert_eq!(tempest.get_battery_voltage("ST-00000512"), Some(2.410));
+        // events already handed over to the channel may be delivered ahead of the most recent events
+        let delivered = queued_timestamps(&mut receiver).await;
+        assert!(delivered.ends_with(&[4, 5]));
+        assert_eq!(delivered.len() as u64 + tempest.dropped_event_count(), 6);
     }
 
     #[tokio::test]
-    async fn get_wind_lull() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn overflow_block() {
+        let (mock, tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .channel_capacity(2)
+                .overflow_policy(OverflowPolicy::Block),
+        )
+        .await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        // the listener waits for the receiver after filling the channel, so only three events are shared
+        let mut shared = send_stalled(&mock, &tempest, port, 5, 3).await;
 
-        assert_eq!(tempest.get_wind_lull("ST-00000512"), Some(0.18));
-    }
+        assert!(
+            time::timeout(Duration::from_millis(100), shared.recv())
+                .await
+                .is_err()
+        );
 
-    #[tokio::test]
-    async fn get_wind_avg() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+        let mut delivered = Vec::new();
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        while delivered.len() < 5 {
+            if let Some(EventType::RapidWind(event)) = receiver.recv().await {
+                delivered.push(event.get_timestamp_checked().unwrap_or_default() - 1493322445);
+            }
+        }
 
-        assert_eq!(tempest.get_wind_avg("ST-00000512"), Some(0.27));
+        assert_eq!(delivered, vec![0, 1, 2, 3, 4]);
+        assert_eq!(tempest.dropped_event_count(), 0);
     }
 
     #[tokio::test]
-    async fn get_wind_gust() {
+    async fn feels_like_extremes() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
+        assert_eq!(tempest.feels_like_extremes("ST-00000512"), None);
+
+        // cold and windy
+        let mut cold = STATION_OBSERVATION;
+        cold[2] = 8.0;
+        cold[7] = -5.0;
+        mock.send(
+            get_station_observation_payload_from("ST-00000512", cold),
+            port,
+        );
         receiver.recv().await;
 
-        assert_eq!(tempest.get_wind_gust("ST-00000512"), Some(0.27));
-    }
+        let chill = wind_chill(-5.0, 8.0);
+        assert_eq!(
+            tempest.feels_like_extremes("ST-00000512"),
+            Some((chill, chill))
+        );
 
-    #[tokio::test]
-    async fn get_wind_direction() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+        // hot and humid
+        let mut hot = STATION_OBSERVATION;
+        hot[0] += 60.0;
+        hot[7] = 35.0;
+        hot[8] = 70.0;
+        mock.send(
+            get_station_observation_payload_from("ST-00000512", hot),
+            port,
+        );
+        receiver.recv().await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
+        // a mild reading widens neither extreme
+        mock.send(get_station_observation_payload(), port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_wind_direction("ST-00000512"), Some(3.0));
+        assert_eq!(
+            tempest.feels_like_extremes("ST-00000512"),
+            Some((chill, heat_index(35.0, 70.0)))
+        );
     }
 
     #[tokio::test]
-    async fn get_wind_speed() {
+    async fn many_stations() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        for station in 0..100 {
+            let serial_number = format!("ST-{station:08}");
+            let payload =
+                get_rapidwind_payload_from(&serial_number, [1588948614.0, station as f64, 90.0]);
+            mock.send(payload.clone(), port);
+            receiver.recv().await;
+        }
 
-        let payload = get_rapidwind_payload();
+        // a repeat of a cached station updates its record rather than adding another
+        let payload = get_rapidwind_payload_from("st-00000042", [1588948617.0, 4.2, 180.0]);
         mock.send(payload.clone(), port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_wind_speed("ST-00000512"), Some(2.3));
+        assert_eq!(tempest.station_count(), 100);
+
+        for station in [0, 42, 99] {
+            let serial_number = format!("ST-{station:08}");
+            let cached = tempest
+                .get_station_by_sn(&serial_number)
+                .expect("Unable to retrieve station");
+            assert_eq!(cached.serial_number, serial_number);
+        }
+
+        assert_eq!(tempest.get_wind_speed("ST-00000042"), Some(4.2));
+        assert!(tempest.get_station_by_sn("ST-00000100").is_none());
+        assert_eq!(tempest.station_serials()[99], "ST-00000099");
     }
 
     #[tokio::test]
-    async fn get_station_pressure() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn send_typed_event() {
+        let (mock, _tempest, mut receiver, port) = test_setup(false).await;
+
+        let event: RapidWindEvent = serde_json::from_value(serde_json::json!({
+            "serial_number": "ST-00000512",
+            "type": "rapid_wind",
+            "hub_sn": "HB-00013030",
+            "ob": [1588948614, 3.1, 270]
+        }))
+        .expect("Unable to build rapid wind event");
+
+        mock.send_event(&EventType::RapidWind(event.clone()), port);
+
+        match receiver.recv().await {
+            Some(EventType::RapidWind(received)) => assert_eq!(received, event),
+            other => panic!("Expected a rapid wind event, received {other:?}"),
+        }
+    }
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+    #[tokio::test]
+    async fn replay_capture_file() {
+        let (_mock, _tempest, mut receiver, port) = test_setup(false).await;
 
-        assert_eq!(tempest.get_station_pressure("ST-00000512"), Some(1017.57));
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/replay.jsonl");
+        let start = Instant::now();
+
+        // one second between packets replayed ten times faster
+        let sent = replay_file(path, port, 10.0)
+            .await
+            .expect("Unable to replay capture file");
+        assert_eq!(sent, 3);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        let mut events = Vec::new();
+        for _ in 0..sent {
+            match receiver.recv().await.expect("Listener stopped") {
+                EventType::RapidWind(_) => events.push("rapid_wind"),
+                EventType::Lightning(_) => events.push("evt_strike"),
+                EventType::HubStatus(_) => events.push("hub_status"),
+                other => panic!("Unexpected event {other:?}"),
+            }
+        }
+        assert_eq!(events, ["rapid_wind", "evt_strike", "hub_status"]);
+
+        assert!(replay_file("missing.jsonl", port, 1.0).await.is_err());
     }
 
     #[tokio::test]
-    async fn get_air_temperature() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+    async fn mock_hub() {
+        let (_mock, _tempest, mut receiver, port) = test_setup(false).await;
+
+        let interval = Duration::from_millis(20);
+        let hub = MockHub::spawn(
+            port,
+            MockHubConfig {
+                station_serial_number: "ST-00000600".to_string(),
+                hub_serial_number: "HB-00000600".to_string(),
+                rapid_wind: interval,
+                observation: interval,
+                device_status: interval,
+                hub_status: interval,
+                lightning: Some(interval),
+            },
+        );
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        let mut seen = [false; 5];
+
+        while !seen.iter().all(|&seen| seen) {
+            let event = time::timeout(Duration::from_secs(5), receiver.recv())
+                .await
+                .expect("Timed out waiting for mock hub events")
+                .expect("Listener stopped");
+
+            let index = match &event {
+                EventType::RapidWind(_) => 0,
+                EventType::Observation(_) => 1,
+                EventType::DeviceStatus(_) => 2,
+                EventType::HubStatus(_) => 3,
+                EventType::Lightning(_) => 4,
+                _ => continue,
+            };
+
+            let expected = if index == 3 {
+                "HB-00000600"
+            } else {
+                "ST-00000600"
+            };
+            assert_eq!(event.get_serial_number(), expected);
+
+            seen[index] = true;
+        }
 
-        assert_eq!(tempest.get_air_temperature("ST-00000512"), Some(22.37));
+        drop(hub);
     }
 
     #[tokio::test]
-    async fn get_lux() {
+    async fn normalized_serial_numbers() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
+        let payload = get_station_observation_payload_from(" st-00000512 ", STATION_OBSERVATION);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
+
         let payload = get_station_observation_payload();
         mock.send(payload.clone(), port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_lux("ST-00000512"), Some(328.0));
+        assert_eq!(tempest.station_count(), 1);
+        assert_eq!(tempest.station_serials(), vec!["ST-00000512"]);
+        assert!(tempest.get_station_by_sn("ST-00000512").is_some());
+        assert!(tempest.get_station_by_sn(" st-00000512").is_some());
+        assert_eq!(tempest.get_stations_by_hub_sn("hb-00013030").len(), 1);
     }
 
     #[tokio::test]
-    async fn get_uv() {
+    async fn hub_radio_healthy() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let payload = get_station_observation_payload();
+        assert_eq!(tempest.hub_radio_healthy("HB-00000001"), None);
+
+        let payload = get_hub_payload_from("HB-00000001", 1495724691, [2, 1, 0, 3, 2839]);
         mock.send(payload.clone(), port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_uv("ST-00000512"), Some(0.03));
-    }
+        let payload = get_hub_payload_from("HB-00000002", 1495724691, [2, 1, 0, 0, 2840]);
+        mock.send(payload.clone(), port);
+        receiver.recv().await;
 
-    #[tokio::test]
-    async fn get_solar_radiation() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+        assert_eq!(tempest.hub_radio_healthy("HB-00000001"), Some(true));
+        assert_eq!(tempest.hub_radio_healthy("HB-00000002"), Some(false));
 
-        let payload = get_station_observation_payload();
+        // the active radio rebooting since the previous status is unhealthy
+        let payload = get_hub_payload_from("HB-00000001", 1495724701, [2, 2, 0, 3, 2839]);
         mock.send(payload.clone(), port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_solar_radiation("ST-00000512"), Some(3.0));
-    }
-
-    #[tokio::test]
-    async fn get_rain_prev_min() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+        assert_eq!(tempest.hub_radio_healthy("HB-00000001"), Some(false));
 
-        let payload = get_station_observation_payload();
+        let payload = get_hub_payload_from("HB-00000001", 1495724711, [2, 2, 0, 3, 2839]);
         mock.send(payload.clone(), port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_rain_prev_min("ST-00000512"), Some(0.0));
+        assert_eq!(tempest.hub_radio_healthy("HB-00000001"), Some(true));
     }
 
     #[tokio::test]
-    async fn get_precip_type() {
+    async fn rain_accumulation() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        assert_eq!(tempest.get_rain_accumulated("ST-00000512"), None);
 
-        assert_eq!(
-            tempest.get_precipitation_type("ST-00000512"),
-            Some(PrecipitationType::None)
+        let mut first = STATION_OBSERVATION;
+        first[12] = 0.25;
+        mock.send(
+            get_station_observation_payload_from("ST-00000512", first),
+            port,
         );
-    }
+        receiver.recv().await;
 
-    #[tokio::test]
-    async fn get_lightning_avg_distance() {
-        let (mock, tempest, mut receiver, port) = test_setup(true).await;
+        // a resent packet is counted once
+        mock.send(
+            get_station_observation_payload_from("ST-00000512", first),
+            port,
+        );
+        receiver.recv().await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
+        let mut second = STATION_OBSERVATION;
+        second[0] += 60.0;
+        second[12] = 0.5;
+        mock.send(
+            get_station_observation_payload_from("ST-00000512", second),
+            port,
+        );
         receiver.recv().await;
 
-        assert_eq!(tempest.get_lightning_avg_distance("ST-00000512"), Some(0.0));
+        assert_eq!(tempest.get_rain_accumulated("ST-00000512"), Some(0.75));
+
+        tempest.reset_rain_accumulation("ST-00000512");
+        assert_eq!(tempest.get_rain_accumulated("ST-00000512"), Some(0.0));
     }
 
     #[tokio::test]
-    async fn get_lightning_count() {
+    async fn rain_accumulation_late_resend() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
+        let mut first = STATION_OBSERVATION;
+        first[12] = 0.25;
+        let mut second = STATION_OBSERVATION;
+        second[0] += 60.0;
+        second[12] = 0.5;
+
+        for obs in [first, second, first] {
+            mock.send(
+                get_station_observation_payload_from("ST-00000512", obs),
+                port,
+            );
+            receiver.recv().await;
+        }
+
+        assert_eq!(tempest.get_rain_accumulated("ST-00000512"), Some(0.75));
+
+        // a resend of the same timestamp with other readings changed is still counted once
+        second[7] += 1.0;
+        mock.send(
+            get_station_observation_payload_from("ST-00000512", second),
+            port,
+        );
         receiver.recv().await;
 
-        assert_eq!(tempest.get_lightning_count("ST-00000512"), Some(0.0));
+        assert_eq!(tempest.get_rain_accumulated("ST-00000512"), Some(0.75));
     }
 
     #[tokio::test]
-    async fn get_lightning_timestamp() {
+    async fn gust_windows() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
-        receiver.recv().await;
+        assert_eq!(tempest.gust_windows("ST-00000512"), None);
 
-        let payload = get_lightning_payload();
-        mock.send(payload.clone(), port);
+        // a strong gust several minutes before a minute of lighter wind with a spike
+        let start = 1493322445.0;
+        mock.send(
+            get_rapidwind_payload_from("ST-00000512", [start, 15.0, 128.0]),
+            port,
+        );
         receiver.recv().await;
 
+        for sample in 0..20 {
+            let speed = if sample == 7 {
+                9.0
+            } else {
+                2.0 + (sample % 3) as f64 * 0.5
+            };
+            let timestamp = start + 300.0 + sample as f64 * 3.0;
+
+            mock.send(
+                get_rapidwind_payload_from("ST-00000512", [timestamp, speed, 128.0]),
+                port,
+            );
+            receiver.recv().await;
+        }
+
         assert_eq!(
-            tempest.get_lightning_timestamp("ST-00000512"),
-            Some(1493322445)
+            tempest.gust_windows("ST-00000512"),
+            Some(GustWindows {
+                max_1min: 9.0,
+                max_10min: 15.0
+            })
         );
     }
 
     #[tokio::test]
-    async fn get_lightning_distance() {
+    async fn get_local_day_rain_accum() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
+        let sky = |timestamp: u64, accumulation: f32| {
+            serde_json::to_vec(&serde_json::json!({
+                "serial_number": "SK-00008453",
+                "type": "obs_sky",
+                "hub_sn": "HB-00000001",
+                "obs": [[timestamp, 9000, 10, 0.0, 2.6, 4.6, 7.4, 187, 3.12, 1, 130, accumulation, 0, 3]],
+                "firmware_revision": 29
+            }))
+            .expect("Unable to serialize payload")
+        };
+
+        mock.send(sky(1493321340, 1.5), port);
         receiver.recv().await;
 
-        let payload = get_lightning_payload();
-        mock.send(payload.clone(), port);
+        assert_eq!(tempest.get_local_day_rain_accum("SK-00008453"), Some(1.5));
+
+        mock.send(sky(1493321400, 4.25), port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_lightning_distance("ST-00000512"), Some(27));
+        assert_eq!(tempest.get_local_day_rain_accum("SK-00008453"), Some(4.25));
+        assert_eq!(tempest.get_local_day_rain_accum("ST-00000512"), None);
     }
 
     #[tokio::test]
-    async fn get_lightning_energy() {
+    async fn lightning_distances_in_miles() {
         let (mock, tempest, mut receiver, port) = test_setup(true).await;
 
-        let payload = get_station_observation_payload();
-        mock.send(payload.clone(), port);
+        let mut observation = STATION_OBSERVATION;
+        observation[14] = 16.0;
+        mock.send(
+            get_station_observation_payload_from("ST-00000512", observation),
+            port,
+        );
         receiver.recv().await;
 
-        let payload = get_lightning_payload();
-        mock.send(payload.clone(), port);
+        assert_eq!(
+            tempest.get_lightning_avg_distance("ST-00000512"),
+            Some(16.0)
+        );
+        let miles = tempest
+            .get_lightning_avg_distance_miles("ST-00000512")
+            .expect("Expected an average distance");
+        assert!((miles - 9.94).abs() < 0.01);
+
+        // the averaged distance does not stand in for a missing strike event
+        assert_eq!(tempest.get_lightning_distance("ST-00000512"), None);
+        assert_eq!(tempest.get_last_strike_distance_miles("ST-00000512"), None);
+
+        let payload = get_lightning_payload_from("ST-00000512", [1493322445, 27, 3848]);
+        mock.send(payload, port);
         receiver.recv().await;
 
-        assert_eq!(tempest.get_lightning_energy("ST-00000512"), Some(3848));
+        assert_eq!(tempest.get_lightning_distance("ST-00000512"), Some(27));
+        let miles = tempest
+            .get_last_strike_distance_miles("ST-00000512")
+            .expect("Expected a strike distance");
+        assert!((miles - 16.78).abs() < 0.01);
+    }
+
+    #[tokio::test]
+    async fn filter_by_hub() {
+        let (mock, _tempest, mut receiver, port) = builder_setup(
+            Tempest::builder()
+                .filter(vec!["hb-00000001"])
+                .filter_by(FilterBy::Hub),
+        )
+        .await;
+
+        // reported through another hub
+        mock.send(get_station_observation_payload(), port);
+        mock.send(
+            get_rapidwind_payload_from("ST-00000512", [1.0, 2.0, 3.0]),
+            port,
+        );
+        mock.send(get_device_payload(), port);
+        mock.send(get_secondary_device_payload(), port);
+
+        let mut serial_numbers = Vec::new();
+        for _ in 0..3 {
+            let event = receiver.recv().await.expect("Unable to receive event");
+            assert_eq!(event.get_hub_sn(), "HB-00000001");
+            serial_numbers.push(event.get_serial_number());
+        }
+        serial_numbers.sort();
+        assert_eq!(
+            serial_numbers,
+            ["AR-00004049", "AR-00004050", "ST-00000512"]
+        );
+
+        assert!(
+            time::timeout(Duration::from_millis(100), receiver.recv())
+                .await
+                .is_err()
+        );
     }
 }
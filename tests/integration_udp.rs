@@ -1,13 +1,18 @@
 use rtempest::mock::MockSender;
 use rtempest::test_common::*;
-use rtempest::{data::EventType, udp::Tempest};
+use rtempest::{
+    data::{EventType, parse_event},
+    udp::Tempest,
+};
 
 const PORT: u16 = 50222;
 
 #[tokio::test]
 async fn udp() {
     let mock = MockSender::bind();
-    let mut receiver = Tempest::listen_udp().await;
+    let mut receiver = Tempest::listen_udp()
+        .await
+        .expect("Unable to bind listener");
 
     mock.send(get_rain_payload(), PORT);
     mock.send(get_lightning_payload(), PORT);
@@ -25,7 +30,7 @@ async fn udp() {
             EventType::Rain(event_data) => {
                 println!("{event_data}");
 
-                if event_data.get_timestamp() == 1493322445 {
+                if event_data.try_get_timestamp() == Ok(1493322445) {
                     success[0] = true;
                     println!("rain");
                 }
@@ -38,7 +43,7 @@ async fn udp() {
             EventType::Lightning(event_data) => {
                 println!("{event_data}");
 
-                if event_data.get_strike_energy() == 3848 {
+                if event_data.try_get_strike_energy() == Ok(3848) {
                     success[1] = true;
                     println!("lightning");
                 }
@@ -126,10 +131,29 @@ async fn udp() {
                     return;
                 }
             }
+            EventType::Unknown { event_type, .. } => {
+                println!("unknown event type {event_type}");
+            }
+            _ => {}
         }
     }
 }
 
-fn finished(success: &Vec<bool>) -> bool {
+fn finished(success: &[bool]) -> bool {
     !success.contains(&false)
 }
+
+// EventType is non_exhaustive, so matches outside the crate compile only with a wildcard arm,
+// which keeps them compiling as variants are added
+#[test]
+fn match_with_wildcard() {
+    let kind = |payload: Vec<u8>| match parse_event(&payload).expect("Unable to parse event") {
+        EventType::Observation(_) => "observation",
+        EventType::RapidWind(_) => "rapid wind",
+        _ => "other",
+    };
+
+    assert_eq!(kind(get_station_observation_payload()), "observation");
+    assert_eq!(kind(get_rapidwind_payload()), "rapid wind");
+    assert_eq!(kind(get_hub_payload()), "other");
+}